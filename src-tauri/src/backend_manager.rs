@@ -2,34 +2,133 @@
 // Starts, monitors, and stops the Python backend service
 
 use std::process::{Command, Child, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
 use std::sync::Mutex;
-use std::path::PathBuf;
-use std::fs;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::time::{Duration, Instant};
 use std::thread;
 
+use tauri::{AppHandle, Emitter};
+
 /// Global backend process handle
 static BACKEND_PROCESS: Mutex<Option<Child>> = Mutex::new(None);
 
+/// Whether the supervised backend is currently up. Read on every poll
+/// instead of re-locking `BACKEND_PROCESS`, since a `Mutex` round-trip per
+/// poll is wasted contention. Set by the spawn path and the monitor thread,
+/// and cleared by `stop_backend`.
+static BACKEND_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Port the currently-running backend was started on, or 0 if none.
+static BACKEND_ACTIVE_PORT: AtomicU16 = AtomicU16::new(0);
+
+/// Set by `stop_backend` before killing the child, so the monitor thread
+/// can tell an intentional shutdown from a crash and skip the auto-restart.
+static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// How many times the supervisor has restarted the backend since it was
+/// first started, for surfacing in the UI and capping retries.
+static RESTART_COUNT: AtomicU16 = AtomicU16::new(0);
+
+/// How long the backend has to stay up after a restart before it's
+/// considered healthy again, resetting `RESTART_COUNT` (and thus the
+/// backoff) back to zero. Without this, `max_retries` bounds restarts over
+/// the process's entire lifetime instead of consecutive crashes, so a
+/// backend that's been fine for weeks but crashes occasionally would
+/// eventually exhaust its budget and stop being supervised.
+const STABILITY_THRESHOLD: Duration = Duration::from_secs(60);
+
+/// How verbosely the supervisor logs its own lifecycle messages, mirroring
+/// pict-rs's request-logging toggle rather than always printing or always
+/// staying silent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogVerbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
 /// Backend configuration
-#[allow(dead_code)]
 pub struct BackendConfig {
-    pub port: u16,
+    /// Candidate ports to allocate the backend onto; the first one found
+    /// unbound via socket enumeration is passed to it explicitly.
+    pub port_range: std::ops::RangeInclusive<u16>,
     pub log_dir: PathBuf,
+    /// Restart the backend automatically if it exits unexpectedly.
+    pub auto_restart: bool,
+    /// Give up after this many consecutive restarts.
+    pub max_retries: u16,
+    /// Starting delay before the first restart attempt.
+    pub base_backoff: Duration,
+    /// Restart delay never grows past this, however many retries have happened.
+    pub max_backoff: Duration,
+    pub log_verbosity: LogVerbosity,
 }
 
 impl Default for BackendConfig {
     fn default() -> Self {
         Self {
-            port: 8765,
+            port_range: 8765..=8865,
             log_dir: dirs::data_dir()
                 .unwrap_or_else(|| PathBuf::from("."))
                 .join("license-wrapper")
                 .join("logs"),
+            auto_restart: true,
+            max_retries: 5,
+            base_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            log_verbosity: LogVerbosity::Normal,
         }
     }
 }
 
+/// Enumerate currently-bound local TCP ports and return the first port in
+/// `range` that isn't one of them, so the backend is launched on a port we
+/// know is actually free rather than racing a hardcoded default against
+/// whatever else is listening.
+fn find_free_port(range: std::ops::RangeInclusive<u16>) -> Option<u16> {
+    use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+    let bound_ports: std::collections::HashSet<u16> =
+        get_sockets_info(AddressFamilyFlags::IPV4, ProtocolFlags::TCP)
+            .map(|sockets| {
+                sockets
+                    .iter()
+                    .filter_map(|socket| match &socket.protocol_socket_info {
+                        ProtocolSocketInfo::Tcp(tcp) => Some(tcp.local_port),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+    range.into_iter().find(|port| !bound_ports.contains(port))
+}
+
+/// Where `run_installer_build` copies the standalone backend executable
+/// (see `commands::compiler::bundle_backend_sidecar`), relative to
+/// `app_path`. Packaged builds ship the backend this way instead of raw
+/// `backend_service.py` + a system Python install, since neither is
+/// guaranteed to exist on the end user's machine.
+const BACKEND_SIDECAR_RELATIVE_DIR: &str = "resources/backend";
+
+/// The sidecar executable's name for the host OS - `.exe` on Windows, bare
+/// elsewhere, matching `bundle_backend_sidecar`'s naming.
+fn backend_sidecar_name() -> &'static str {
+    if cfg!(target_os = "windows") { "backend_service.exe" } else { "backend_service" }
+}
+
+/// Look for a bundled backend sidecar under `app_path`, returning its path
+/// if present. Checked before falling back to the dev-time
+/// `backend_service.py` + system-Python path, so a packaged install never
+/// has to locate a Python interpreter that may not be there.
+fn find_bundled_backend_sidecar(app_path: &Path) -> Option<PathBuf> {
+    let candidate = app_path.join(BACKEND_SIDECAR_RELATIVE_DIR).join(backend_sidecar_name());
+    candidate.exists().then_some(candidate)
+}
+
 /// Find Python executable
 pub fn find_python() -> Option<PathBuf> {
     // Try common Python locations on Windows
@@ -38,7 +137,7 @@ pub fn find_python() -> Option<PathBuf> {
         "python3",
         "py",
     ];
-    
+
     for candidate in candidates {
         if let Ok(output) = Command::new(candidate)
             .args(["--version"])
@@ -53,7 +152,7 @@ pub fn find_python() -> Option<PathBuf> {
             }
         }
     }
-    
+
     None
 }
 
@@ -68,6 +167,13 @@ fn get_port_file() -> PathBuf {
 
 /// Read backend port from port file
 pub fn get_backend_port() -> Option<u16> {
+    // Prefer the port of the backend this process started; the port file
+    // is only consulted when none is running.
+    let port = BACKEND_ACTIVE_PORT.load(Ordering::SeqCst);
+    if port != 0 {
+        return Some(port);
+    }
+
     let port_file = get_port_file();
     if port_file.exists() {
         if let Ok(content) = fs::read_to_string(&port_file) {
@@ -83,8 +189,43 @@ pub fn get_backend_url() -> String {
     format!("http://127.0.0.1:{}", port)
 }
 
-/// Start the backend service
-pub fn start_backend(app_path: &PathBuf) -> Result<u16, String> {
+/// Open today's rotating log file for a given stream (`stdout`/`stderr`),
+/// appending to it if the process already wrote to it earlier today.
+fn open_rotating_log(log_dir: &Path, stream_name: &str) -> std::io::Result<fs::File> {
+    fs::create_dir_all(log_dir)?;
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    let path = log_dir.join(format!("backend-{stream_name}-{date}.log"));
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Drain a child's stdout/stderr pipe line-by-line into a timestamped,
+/// date-rotated log file until the pipe closes (the process exited or
+/// closed the handle).
+fn spawn_log_reader<R: std::io::Read + Send + 'static>(
+    reader: R,
+    log_dir: PathBuf,
+    stream_name: &'static str,
+) {
+    thread::spawn(move || {
+        let mut log_file = match open_rotating_log(&log_dir, stream_name) {
+            Ok(f) => f,
+            Err(e) => {
+                eprintln!("[BackendManager] Failed to open {stream_name} log: {e}");
+                return;
+            }
+        };
+
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else { break };
+            let timestamp = chrono::Utc::now().to_rfc3339();
+            let _ = writeln!(log_file, "[{timestamp}] {line}");
+        }
+    });
+}
+
+/// Start the backend service, spawning log-capture readers and a monitor
+/// thread that restarts it on an unexpected crash.
+pub fn start_backend(app: AppHandle, app_path: &PathBuf, config: BackendConfig) -> Result<u16, String> {
     // Check if already running
     if is_backend_running() {
         if let Some(port) = get_backend_port() {
@@ -92,99 +233,261 @@ pub fn start_backend(app_path: &PathBuf) -> Result<u16, String> {
             return Ok(port);
         }
     }
-    
-    // Find Python
-    let python = find_python().ok_or_else(|| {
-        "Python not found. Please install Python 3.12+ from python.org".to_string()
-    })?;
-    
-    println!("[BackendManager] Found Python: {:?}", python);
-    
-    // Find backend_service.py
-    let backend_script = app_path.join("backend_service.py");
-    if !backend_script.exists() {
-        return Err(format!("Backend script not found: {:?}", backend_script));
-    }
-    
-    println!("[BackendManager] Starting backend from: {:?}", backend_script);
-    
-    // Start the backend process
-    let child = Command::new(&python)
-        .arg(&backend_script)
-        .arg("--auto-port")
+
+    STOP_REQUESTED.store(false, Ordering::SeqCst);
+    RESTART_COUNT.store(0, Ordering::SeqCst);
+
+    let port = spawn_backend_process(app.clone(), app_path, &config)?;
+
+    let app_path = app_path.clone();
+    thread::spawn(move || monitor_backend(app, app_path, config));
+
+    Ok(port)
+}
+
+/// Launch the Python backend process, wire up its log readers, store the
+/// child handle, and wait for it to report readiness.
+fn spawn_backend_process(app: AppHandle, app_path: &PathBuf, config: &BackendConfig) -> Result<u16, String> {
+    // Packaged builds run the bundled sidecar binary directly; dev runs
+    // fall back to the system Python interpreter plus backend_service.py,
+    // same as before this subsystem existed.
+    let (program, leading_args): (PathBuf, Vec<PathBuf>) = if let Some(sidecar) = find_bundled_backend_sidecar(app_path) {
+        if config.log_verbosity != LogVerbosity::Quiet {
+            println!("[BackendManager] Using bundled backend sidecar: {:?}", sidecar);
+        }
+        (sidecar, Vec::new())
+    } else {
+        let python = find_python().ok_or_else(|| {
+            "Python not found. Please install Python 3.12+ from python.org".to_string()
+        })?;
+
+        if config.log_verbosity != LogVerbosity::Quiet {
+            println!("[BackendManager] Found Python: {:?}", python);
+        }
+
+        let backend_script = app_path.join("backend_service.py");
+        if !backend_script.exists() {
+            return Err(format!(
+                "Backend not found: no bundled sidecar at '{}/{}' and no dev script at {:?}",
+                BACKEND_SIDECAR_RELATIVE_DIR, backend_sidecar_name(), backend_script
+            ));
+        }
+
+        if config.log_verbosity != LogVerbosity::Quiet {
+            println!("[BackendManager] Starting backend from: {:?}", backend_script);
+        }
+
+        (python, vec![backend_script])
+    };
+
+    let port = find_free_port(config.port_range.clone())
+        .ok_or_else(|| "No free port available in the configured range".to_string())?;
+    let instance_id = uuid::Uuid::new_v4().to_string();
+
+    let mut child = Command::new(&program)
+        .args(&leading_args)
+        .arg("--port")
+        .arg(port.to_string())
+        .arg("--instance-id")
+        .arg(&instance_id)
         .current_dir(app_path)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| format!("Failed to start backend: {}", e))?;
-    
-    // Store the process handle
+
+    if let Some(stdout) = child.stdout.take() {
+        spawn_log_reader(stdout, config.log_dir.clone(), "stdout");
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_log_reader(stderr, config.log_dir.clone(), "stderr");
+    }
+
     {
         let mut process = BACKEND_PROCESS.lock().map_err(|e| e.to_string())?;
         *process = Some(child);
     }
-    
-    // Wait for backend to be ready (check port file)
+    BACKEND_RUNNING.store(true, Ordering::SeqCst);
+
+    // Wait for the backend to come up and confirm it's *our* child
+    // answering on that port, not some other process that happened to be
+    // listening there.
     let mut attempts = 0;
     let max_attempts = 30; // 15 seconds total
-    
+
     while attempts < max_attempts {
         thread::sleep(Duration::from_millis(500));
-        
-        if let Some(port) = get_backend_port() {
-            // Verify backend is responding
-            if check_backend_health_sync(port) {
+
+        if check_backend_health_sync(port, &instance_id) {
+            BACKEND_ACTIVE_PORT.store(port, Ordering::SeqCst);
+            write_port_file(port);
+            if config.log_verbosity != LogVerbosity::Quiet {
                 println!("[BackendManager] Backend ready on port {}", port);
-                return Ok(port);
             }
+            return Ok(port);
         }
-        
+
         attempts += 1;
     }
-    
+
+    BACKEND_RUNNING.store(false, Ordering::SeqCst);
+    let _ = app.emit("backend-restarting", serde_json::json!({
+        "reason": "startup timeout",
+    }));
     Err("Backend failed to start within timeout".to_string())
 }
 
-/// Check if backend is healthy (sync version)
-fn check_backend_health_sync(port: u16) -> bool {
-    // Simple TCP connection check
-    use std::net::TcpStream;
-    
-    TcpStream::connect(format!("127.0.0.1:{}", port))
-        .map(|_| true)
-        .unwrap_or(false)
+fn write_port_file(port: u16) {
+    let port_file = get_port_file();
+    if let Some(parent) = port_file.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(port_file, port.to_string());
 }
 
-/// Check if backend is running
-pub fn is_backend_running() -> bool {
-    let mut process = match BACKEND_PROCESS.lock() {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
-    
-    if let Some(ref mut child) = *process {
-        match child.try_wait() {
-            Ok(None) => true, // Still running
-            Ok(Some(_)) => {
-                // Process exited
-                *process = None;
-                false
+fn read_port_file() -> Option<u16> {
+    let port_file = get_port_file();
+    if port_file.exists() {
+        if let Ok(content) = fs::read_to_string(&port_file) {
+            return content.trim().parse().ok();
+        }
+    }
+    None
+}
+
+/// Poll the child on an interval and, if it exits without an explicit
+/// `stop_backend` call, restart it with capped exponential backoff up to
+/// `config.max_retries` attempts, emitting a `backend-restarting` event
+/// each time so the UI can reflect it.
+fn monitor_backend(app: AppHandle, app_path: PathBuf, config: BackendConfig) {
+    let mut running_since = Instant::now();
+
+    loop {
+        thread::sleep(Duration::from_secs(1));
+
+        let exited = {
+            let mut process = match BACKEND_PROCESS.lock() {
+                Ok(p) => p,
+                Err(_) => return,
+            };
+            match process.as_mut() {
+                Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                None => true,
             }
-            Err(_) => false,
+        };
+
+        if !exited {
+            // The backend has run without crashing for long enough since its
+            // last (re)start to no longer count today's restarts against a
+            // future, unrelated crash.
+            if running_since.elapsed() >= STABILITY_THRESHOLD && RESTART_COUNT.load(Ordering::SeqCst) != 0 {
+                RESTART_COUNT.store(0, Ordering::SeqCst);
+            }
+            continue;
         }
-    } else {
-        // Check if port file exists (backend might be running from previous session)
-        if let Some(port) = get_backend_port() {
-            return check_backend_health_sync(port);
+
+        BACKEND_RUNNING.store(false, Ordering::SeqCst);
+        BACKEND_ACTIVE_PORT.store(0, Ordering::SeqCst);
+
+        if STOP_REQUESTED.load(Ordering::SeqCst) {
+            return;
+        }
+
+        if !config.auto_restart {
+            return;
+        }
+
+        let attempt = RESTART_COUNT.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt > config.max_retries {
+            let _ = app.emit("backend-restarting", serde_json::json!({
+                "attempt": attempt,
+                "giving_up": true,
+            }));
+            return;
+        }
+
+        let backoff = config.base_backoff.saturating_mul(1 << (attempt.saturating_sub(1).min(16)));
+        let backoff = backoff.min(config.max_backoff);
+
+        let _ = app.emit("backend-restarting", serde_json::json!({
+            "attempt": attempt,
+            "max_retries": config.max_retries,
+            "backoff_ms": backoff.as_millis(),
+            "giving_up": false,
+        }));
+
+        thread::sleep(backoff);
+
+        if STOP_REQUESTED.load(Ordering::SeqCst) {
+            return;
         }
-        false
+
+        match spawn_backend_process(app.clone(), &app_path, &config) {
+            Ok(_) => {
+                running_since = Instant::now();
+            }
+            Err(e) => {
+                eprintln!("[BackendManager] Restart attempt {attempt} failed: {e}");
+            }
+        }
+    }
+}
+
+/// Probe `/health` on `port` and confirm the response body contains
+/// `expected_instance_id`, the `instance_id` our child reports -
+/// otherwise a TCP connect alone can't tell our child apart from any other
+/// process that happened to grab the port.
+fn check_backend_health_sync(port: u16, expected_instance_id: &str) -> bool {
+    match http_get(port, "/health") {
+        Some(body) => body.contains(expected_instance_id),
+        None => false,
+    }
+}
+
+/// Minimal blocking HTTP/1.1 GET over a raw `TcpStream`, returning the
+/// response body on a 200 - enough to read a small JSON `/health` payload
+/// without pulling in a second HTTP client alongside `reqwest`.
+fn http_get(port: u16, path: &str) -> Option<String> {
+    use std::io::Read;
+    use std::net::TcpStream;
+
+    let mut stream = TcpStream::connect(format!("127.0.0.1:{}", port)).ok()?;
+    stream.set_read_timeout(Some(Duration::from_secs(2))).ok()?;
+    stream.set_write_timeout(Some(Duration::from_secs(2))).ok()?;
+
+    let request = format!("GET {path} HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+
+    let (status_line, body) = response.split_once("\r\n\r\n").unwrap_or((response.as_str(), ""));
+    if !status_line.contains(" 200 ") {
+        return None;
     }
+    Some(body.to_string())
+}
+
+/// Check if backend is running
+pub fn is_backend_running() -> bool {
+    if BACKEND_RUNNING.load(Ordering::SeqCst) {
+        return true;
+    }
+
+    // Backend might be running from a previous session with no monitor
+    // thread attached in this process, so there's no instance id on hand
+    // to compare against - fall back to plain reachability.
+    if let Some(port) = read_port_file() {
+        return http_get(port, "/health").is_some();
+    }
+    false
 }
 
 /// Stop the backend service
 pub fn stop_backend() {
     println!("[BackendManager] Stopping backend...");
-    
+    STOP_REQUESTED.store(true, Ordering::SeqCst);
+
     if let Ok(mut process) = BACKEND_PROCESS.lock() {
         if let Some(ref mut child) = *process {
             // Try graceful shutdown first
@@ -195,25 +498,28 @@ pub fn stop_backend() {
                     .args(["/PID", &child.id().to_string(), "/T"])
                     .output();
             }
-            
+
             #[cfg(not(target_os = "windows"))]
             {
                 // On Unix, send SIGTERM
                 let _ = child.kill();
             }
-            
+
             // Wait for process to exit
             let _ = child.wait();
             *process = None;
         }
     }
-    
+
+    BACKEND_RUNNING.store(false, Ordering::SeqCst);
+    BACKEND_ACTIVE_PORT.store(0, Ordering::SeqCst);
+
     // Clean up port file
     let port_file = get_port_file();
     if port_file.exists() {
         let _ = fs::remove_file(port_file);
     }
-    
+
     println!("[BackendManager] Backend stopped");
 }
 
@@ -222,18 +528,19 @@ pub fn stop_backend() {
 pub fn check_backend_status() -> Result<serde_json::Value, String> {
     let running = is_backend_running();
     let port = get_backend_port();
-    
+
     Ok(serde_json::json!({
         "running": running,
         "port": port,
-        "url": get_backend_url()
+        "url": get_backend_url(),
+        "restart_count": RESTART_COUNT.load(Ordering::SeqCst),
     }))
 }
 
 /// Tauri command: Start backend
 #[tauri::command]
-pub fn start_backend_service(app_path: String) -> Result<u16, String> {
-    start_backend(&PathBuf::from(app_path))
+pub fn start_backend_service(app: AppHandle, app_path: String) -> Result<u16, String> {
+    start_backend(app, &PathBuf::from(app_path), BackendConfig::default())
 }
 
 /// Tauri command: Stop backend
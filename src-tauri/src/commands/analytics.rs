@@ -0,0 +1,194 @@
+// Background analytics sync worker - `analytics_events`/`idx_analytics_synced`
+// were already in the schema for deferred cloud upload, but nothing ever
+// drained them. Following pict-rs's backgrounded-query pattern, a worker
+// wakes up on an interval (and once more on app shutdown) and POSTs
+// whatever's queued to `{api_url}/analytics`, backing off when the server
+// is unreachable so an offline session just accumulates events.
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::db::DbCtx;
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(60);
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+const BATCH_SIZE: i64 = 50;
+
+#[derive(Debug, Clone, Serialize)]
+struct AnalyticsEventRow {
+    id: i64,
+    event_type: String,
+    project_id: Option<String>,
+    metadata: Option<String>,
+    created_at: String,
+}
+
+/// Pending vs already-delivered counts, for a diagnostics panel.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalyticsSyncCounts {
+    pub pending: i64,
+    pub synced: i64,
+}
+
+/// Queue one analytics event locally; the background worker picks it up
+/// and marks it `synced` once it's been delivered.
+#[tauri::command]
+pub async fn track_event(
+    db: tauri::State<'_, DbCtx>,
+    event_type: String,
+    project_id: Option<String>,
+    metadata: Option<serde_json::Value>,
+) -> Result<(), String> {
+    let conn = db.get()?;
+    let metadata_json = metadata.map(|m| m.to_string());
+    conn.execute(
+        "INSERT INTO analytics_events (event_type, project_id, metadata) VALUES (?1, ?2, ?3)",
+        params![event_type, project_id, metadata_json],
+    )
+    .map_err(|e| format!("Failed to record analytics event: {e}"))?;
+    Ok(())
+}
+
+/// Report how many events are waiting to sync vs already delivered.
+#[tauri::command]
+pub async fn get_analytics_sync_status(db: tauri::State<'_, DbCtx>) -> Result<AnalyticsSyncCounts, String> {
+    let conn = db.get()?;
+    let pending = conn
+        .query_row("SELECT COUNT(*) FROM analytics_events WHERE synced = 0", [], |r| r.get(0))
+        .map_err(|e| format!("Failed to count pending analytics events: {e}"))?;
+    let synced = conn
+        .query_row("SELECT COUNT(*) FROM analytics_events WHERE synced = 1", [], |r| r.get(0))
+        .map_err(|e| format!("Failed to count synced analytics events: {e}"))?;
+    Ok(AnalyticsSyncCounts { pending, synced })
+}
+
+/// `api_url`/`auth_token` straight from the `settings` table, read fresh on
+/// every sync attempt since the user can change either at any time.
+fn read_sync_settings(db: &DbCtx) -> Result<(String, String), String> {
+    let conn = db.get()?;
+    let read = |key: &str| -> Result<String, String> {
+        conn.query_row("SELECT value FROM settings WHERE key = ?1", params![key], |r| r.get::<_, String>(0))
+            .optional()
+            .map_err(|e| format!("Failed to read '{key}' setting: {e}"))
+            .map(|v| v.unwrap_or_default())
+    };
+
+    let api_url = read("api_url")?;
+    let auth_token = read("auth_token")?;
+    Ok((api_url, auth_token))
+}
+
+fn fetch_unsynced(db: &DbCtx) -> Result<Vec<AnalyticsEventRow>, String> {
+    let conn = db.get()?;
+    let mut stmt = conn
+        .prepare("SELECT id, event_type, project_id, metadata, created_at FROM analytics_events WHERE synced = 0 ORDER BY id ASC LIMIT ?1")
+        .map_err(|e| format!("Failed to query analytics queue: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![BATCH_SIZE], |row| {
+            Ok(AnalyticsEventRow {
+                id: row.get(0)?,
+                event_type: row.get(1)?,
+                project_id: row.get(2)?,
+                metadata: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query analytics queue: {e}"))?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(|e| format!("Failed to read analytics queue: {e}"))
+}
+
+fn mark_synced(db: &DbCtx, ids: &[i64]) -> Result<(), String> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let conn = db.get()?;
+    let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+    let sql = format!("UPDATE analytics_events SET synced = 1 WHERE id IN ({placeholders})");
+    let rusqlite_params: Vec<&dyn rusqlite::ToSql> = ids.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+    conn.execute(&sql, rusqlite_params.as_slice())
+        .map_err(|e| format!("Failed to mark analytics events synced: {e}"))?;
+    Ok(())
+}
+
+/// POST one batch of unsynced rows and, on any 2xx response, mark the whole
+/// batch as synced. Returns how many rows were sent, so the caller knows
+/// whether to keep draining.
+async fn sync_batch(db: &DbCtx, api_url: &str, auth_token: &str) -> Result<usize, String> {
+    let rows = fetch_unsynced(db)?;
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{api_url}/analytics"))
+        .header("Authorization", format!("Bearer {auth_token}"))
+        .json(&rows)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach analytics endpoint: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Analytics endpoint returned {}", response.status()));
+    }
+
+    let ids: Vec<i64> = rows.iter().map(|r| r.id).collect();
+    mark_synced(db, &ids)?;
+    Ok(ids.len())
+}
+
+/// Drain the whole queue in `BATCH_SIZE` chunks, stopping as soon as a
+/// batch fails so the caller can back off instead of hammering a
+/// server that's down.
+async fn drain_queue(db: &DbCtx, api_url: &str, auth_token: &str) -> Result<(), String> {
+    loop {
+        let sent = sync_batch(db, api_url, auth_token).await?;
+        if sent == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Background task: every [`SYNC_INTERVAL`] (doubling up to [`MAX_BACKOFF`]
+/// after a failed attempt), drain whatever's queued in `analytics_events`.
+/// Skips the attempt entirely if no auth token is configured yet.
+pub fn spawn_worker(db: DbCtx) {
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = SYNC_INTERVAL;
+        loop {
+            tokio::time::sleep(backoff).await;
+
+            let (api_url, auth_token) = match read_sync_settings(&db) {
+                Ok(settings) => settings,
+                Err(e) => {
+                    eprintln!("[AnalyticsSync] {e}");
+                    continue;
+                }
+            };
+            if auth_token.is_empty() {
+                backoff = SYNC_INTERVAL;
+                continue;
+            }
+
+            match drain_queue(&db, &api_url, &auth_token).await {
+                Ok(()) => backoff = SYNC_INTERVAL,
+                Err(e) => {
+                    eprintln!("[AnalyticsSync] {e}");
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                }
+            }
+        }
+    });
+}
+
+/// Best-effort final flush, called from the app's shutdown handler so
+/// events queued right before quitting aren't stuck until next launch.
+pub fn flush_on_exit(db: &DbCtx) {
+    let Ok((api_url, auth_token)) = read_sync_settings(db) else { return };
+    if auth_token.is_empty() {
+        return;
+    }
+    let _ = tauri::async_runtime::block_on(drain_queue(db, &api_url, &auth_token));
+}
@@ -0,0 +1,283 @@
+// Compilation benchmark/workload runner - borrowing MeiliSearch's `xtask
+// bench` design, this drives one or more compiles from a JSON workload
+// file, times each one, and snapshots the machine it ran on into
+// `compile_history` so Nuitka compile performance can be compared across
+// machines instead of only recording pass/fail.
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::db::DbCtx;
+
+use super::compiler::{self, StartCompileRequest};
+use super::downloader;
+use super::jobs::JobRegistry;
+
+/// One project to compile as part of a workload run. `request` is the same
+/// shape `run_nuitka_compilation` already takes; `project_id`/`server_url`/
+/// `auth_token` are only needed when `request.project_path` isn't already
+/// on disk and must be fetched through the existing downloader first.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkloadEntry {
+    pub label: Option<String>,
+    pub project_id: Option<String>,
+    pub server_url: Option<String>,
+    pub auth_token: Option<String>,
+    pub request: StartCompileRequest,
+}
+
+/// A workload file: a tag for the run plus the projects to compile, and an
+/// optional URL to POST the aggregated report to once every entry is done.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub benchmark_tag: Option<String>,
+    pub report_url: Option<String>,
+    pub projects: Vec<WorkloadEntry>,
+}
+
+/// The host this benchmark ran on, captured the way MeiliSearch's
+/// `env_info.rs` snapshots a machine for a bench report.
+#[derive(Debug, Clone, Serialize)]
+pub struct HostEnvironment {
+    pub os: String,
+    pub arch: String,
+    pub cpu_model: Option<String>,
+    pub cpu_cores: u32,
+    pub ram_mb: Option<u64>,
+    pub python_version: Option<String>,
+    pub nuitka_version: Option<String>,
+}
+
+/// Timing and outcome for a single workload entry.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkRunResult {
+    pub label: String,
+    pub project_path: String,
+    pub success: bool,
+    pub duration_ms: u64,
+    pub output_path: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// Full result of [`run_compile_benchmark`]: the host snapshot plus every
+/// run's timing, in workload order.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub host: HostEnvironment,
+    pub runs: Vec<BenchmarkRunResult>,
+}
+
+/// CPU model string, probed per OS rather than through a new dependency:
+/// `sysctl` on macOS, `/proc/cpuinfo` on Linux, `PROCESSOR_IDENTIFIER` on
+/// Windows.
+async fn cpu_model() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        let output = tokio::process::Command::new("sysctl").args(["-n", "machdep.cpu.brand_string"]).output().await.ok()?;
+        return Some(String::from_utf8_lossy(&output.stdout).trim().to_string());
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let info = tokio::fs::read_to_string("/proc/cpuinfo").await.ok()?;
+        return info.lines().find_map(|l| l.strip_prefix("model name").map(|rest| rest.trim_start_matches([':', ' ']).to_string()));
+    }
+    #[cfg(target_os = "windows")]
+    {
+        return std::env::var("PROCESSOR_IDENTIFIER").ok();
+    }
+    #[allow(unreachable_code)]
+    None
+}
+
+/// Total installed RAM in megabytes, probed with the same per-OS approach
+/// as [`cpu_model`].
+async fn ram_mb() -> Option<u64> {
+    #[cfg(target_os = "macos")]
+    {
+        let output = tokio::process::Command::new("sysctl").args(["-n", "hw.memsize"]).output().await.ok()?;
+        let bytes: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+        return Some(bytes / 1024 / 1024);
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let info = tokio::fs::read_to_string("/proc/meminfo").await.ok()?;
+        let kb: u64 = info
+            .lines()
+            .find_map(|l| l.strip_prefix("MemTotal:"))?
+            .trim()
+            .trim_end_matches("kB")
+            .trim()
+            .parse()
+            .ok()?;
+        return Some(kb / 1024);
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let output = tokio::process::Command::new("wmic").args(["OS", "get", "TotalVisibleMemorySize"]).output().await.ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let kb: u64 = text.lines().nth(1)?.trim().parse().ok()?;
+        return Some(kb / 1024);
+    }
+    #[allow(unreachable_code)]
+    None
+}
+
+/// Snapshot the host this benchmark is about to run on: OS/arch, CPU, RAM,
+/// and the Python/Nuitka versions the compile pipeline will actually use.
+async fn capture_host_environment() -> HostEnvironment {
+    let env_report = compiler::environment_report().await.ok();
+
+    HostEnvironment {
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        cpu_model: cpu_model().await,
+        cpu_cores: std::thread::available_parallelism().map(|n| n.get() as u32).unwrap_or(1),
+        ram_mb: ram_mb().await,
+        python_version: env_report.as_ref().and_then(|r| r.python.version.clone()),
+        nuitka_version: env_report.as_ref().and_then(|r| r.nuitka.version.clone()),
+    }
+}
+
+/// Make sure `entry.request.project_path` exists locally, downloading it
+/// first through the existing downloader when a `project_id` is given and
+/// nothing's there yet.
+async fn resolve_project_path(window: &tauri::Window, entry: &WorkloadEntry) -> Result<String, String> {
+    let path = std::path::Path::new(&entry.request.project_path);
+    if path.exists() {
+        return Ok(entry.request.project_path.clone());
+    }
+
+    let project_id = entry.project_id.clone().ok_or_else(|| {
+        format!("Project path {} doesn't exist and no project_id was given to download it", entry.request.project_path)
+    })?;
+    let server_url = entry.server_url.clone().ok_or_else(|| "server_url is required to download this project".to_string())?;
+    let auth_token = entry.auth_token.clone().ok_or_else(|| "auth_token is required to download this project".to_string())?;
+
+    downloader::download_and_prepare_for_compile(
+        window.clone(),
+        project_id,
+        server_url,
+        auth_token,
+        Some(entry.request.project_path.clone()),
+        None,
+    )
+    .await
+}
+
+/// Record one benchmark run into `compile_history`, augmented with the
+/// host snapshot and the workload's tag, so performance can be compared
+/// across machines later.
+fn record_run(db: &DbCtx, host: &HostEnvironment, tag: Option<&str>, request: &StartCompileRequest, result: &BenchmarkRunResult) -> Result<(), String> {
+    let conn = db.get()?;
+    let id = uuid::Uuid::new_v4().to_string();
+    let status = if result.success { "completed" } else { "failed" };
+
+    conn.execute(
+        "INSERT INTO compile_history (
+            id, project_id, entry_file, output_name, status, completed_at,
+            output_path, error_message, duration_ms, host_os, host_cpu_model,
+            host_cpu_cores, host_ram_mb, python_version, nuitka_version, benchmark_tag
+         ) VALUES (?1, ?2, ?3, ?4, ?5, CURRENT_TIMESTAMP, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        params![
+            id,
+            request.project_path,
+            request.entry_file,
+            request.output_name,
+            status,
+            result.output_path,
+            result.error_message,
+            result.duration_ms as i64,
+            host.os,
+            host.cpu_model,
+            host.cpu_cores,
+            host.ram_mb.map(|v| v as i64),
+            host.python_version,
+            host.nuitka_version,
+            tag,
+        ],
+    )
+    .map_err(|e| format!("Failed to record benchmark run: {e}"))?;
+
+    Ok(())
+}
+
+/// Load a workload file, compile every listed project (downloading first
+/// if needed), time each run, store it in `compile_history` alongside the
+/// host snapshot, and optionally POST the aggregated report to
+/// `workload.report_url` so compile performance can be compared across
+/// machines.
+#[tauri::command]
+pub async fn run_compile_benchmark(
+    window: tauri::Window,
+    db: tauri::State<'_, DbCtx>,
+    registry: tauri::State<'_, JobRegistry>,
+    workload_path: String,
+) -> Result<BenchmarkReport, String> {
+    let contents = tokio::fs::read_to_string(&workload_path)
+        .await
+        .map_err(|e| format!("Failed to read workload file {workload_path}: {e}"))?;
+    let workload: Workload = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse workload file {workload_path}: {e}"))?;
+
+    let host = capture_host_environment().await;
+    let mut runs = Vec::with_capacity(workload.projects.len());
+
+    for entry in &workload.projects {
+        let label = entry.label.clone().unwrap_or_else(|| entry.request.entry_file.clone());
+
+        let project_path = match resolve_project_path(&window, entry).await {
+            Ok(path) => path,
+            Err(e) => {
+                let result = BenchmarkRunResult {
+                    label,
+                    project_path: entry.request.project_path.clone(),
+                    success: false,
+                    duration_ms: 0,
+                    output_path: None,
+                    error_message: Some(e),
+                };
+                let _ = record_run(&db, &host, workload.benchmark_tag.as_deref(), &entry.request, &result);
+                runs.push(result);
+                continue;
+            }
+        };
+
+        let mut request = entry.request.clone();
+        request.project_path = project_path;
+
+        let started = Instant::now();
+        let outcome = compiler::run_nuitka_compilation(window.clone(), request.clone(), registry.clone()).await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        let result = match outcome {
+            Ok(output_path) => BenchmarkRunResult {
+                label,
+                project_path: request.project_path.clone(),
+                success: true,
+                duration_ms,
+                output_path: Some(output_path),
+                error_message: None,
+            },
+            Err(e) => BenchmarkRunResult {
+                label,
+                project_path: request.project_path.clone(),
+                success: false,
+                duration_ms,
+                output_path: None,
+                error_message: Some(e),
+            },
+        };
+
+        let _ = record_run(&db, &host, workload.benchmark_tag.as_deref(), &request, &result);
+        runs.push(result);
+    }
+
+    let report = BenchmarkReport { host, runs };
+
+    if let Some(report_url) = &workload.report_url {
+        let client = reqwest::Client::new();
+        let _ = client.post(report_url).json(&report).send().await;
+    }
+
+    Ok(report)
+}
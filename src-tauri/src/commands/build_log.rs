@@ -0,0 +1,65 @@
+// NDJSON build-event log written alongside the Tauri progress/result
+// events, so a headless or CI invocation (or a bug report) has a stable,
+// parseable artifact instead of only an in-process window event.
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+#[derive(Serialize)]
+struct BuildEvent<'a> {
+    job_id: &'a str,
+    stage: &'a str,
+    progress: Option<u32>,
+    message: &'a str,
+    level: &'a str,
+    source: Option<&'a str>,
+    timestamp: String,
+}
+
+/// Appends one JSON object per line to `build.ndjson` in the job's output
+/// directory. Only created when `StartCompileRequest.log_format` opts in
+/// with `"ndjson"`.
+pub struct BuildEventLog {
+    file: Mutex<File>,
+}
+
+impl BuildEventLog {
+    /// Open (creating/truncating) `build.ndjson` under `dir`, or return
+    /// `None` if `log_format` didn't ask for NDJSON output.
+    pub fn open(log_format: Option<&str>, dir: &Path) -> Option<Self> {
+        if log_format != Some("ndjson") {
+            return None;
+        }
+
+        std::fs::create_dir_all(dir).ok()?;
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(dir.join("build.ndjson"))
+            .ok()?;
+
+        Some(Self { file: Mutex::new(file) })
+    }
+
+    /// Append one event line. Errors are swallowed - like the `window.emit`
+    /// calls alongside this, losing a log line shouldn't fail the build.
+    pub fn write(&self, job_id: &str, stage: &str, progress: Option<u32>, message: &str, level: &str, source: Option<&str>) {
+        let event = BuildEvent {
+            job_id,
+            stage,
+            progress,
+            message,
+            level,
+            source,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let Ok(line) = serde_json::to_string(&event) else { return };
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
@@ -1,12 +1,19 @@
 // Compiler commands for Nuitka integration with real-time progress
 // Uses tokio::process for non-blocking async execution
 use serde::{Deserialize, Serialize};
+use std::io::Write;
 use std::path::PathBuf;
-use tauri::Emitter;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, Manager};
 use tokio::process::Command;
 use tokio::io::{AsyncBufReadExt, BufReader};
 
-#[derive(Debug, Deserialize)]
+use super::build_log::BuildEventLog;
+use super::jobs::JobRegistry;
+use super::toolchain::{self, ToolKind};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StartCompileRequest {
     pub project_path: String,
     pub entry_file: String,
@@ -25,9 +32,20 @@ pub struct StartCompileRequest {
     pub env_values: Option<std::collections::HashMap<String, String>>, // .env values to bake in
     pub install_requirements: Option<bool>,        // Auto-install deps before compile
     pub requirements_path: Option<String>,         // Path to requirements.txt
+    pub extra_index_urls: Option<Vec<String>>,     // Private package indexes, passed as --extra-index-url
     pub build_frontend: Option<bool>,              // Build frontend before compile
     pub frontend_dir: Option<String>,              // Frontend directory (e.g., "frontend")
     pub create_launcher: Option<bool>,             // Create launcher batch file
+    // Compile target: "windows" | "linux" | "macos". Picks the
+    // platform-specific flags and output naming. A "windows" target on a
+    // non-Windows host is built inside a Docker cross-compile container;
+    // any other target that differs from the host is rejected.
+    // Defaults to the host OS when omitted.
+    pub target_os: Option<String>,
+    // Hash every file passed via include_data_dirs/include_data_files at
+    // build time into a bundled manifest, and have the wrapper refuse to
+    // run if the extracted data doesn't match it at startup.
+    pub integrity_check: Option<bool>,
     // Bundle requirements.txt for first-run installation
     pub bundle_requirements: Option<bool>,         // Bundle requirements.txt with output
     // Separate frontend handling  
@@ -35,6 +53,96 @@ pub struct StartCompileRequest {
     // Demo mode configuration
     pub demo_mode: Option<bool>,                   // Enable demo/trial mode
     pub demo_duration_minutes: Option<u32>,        // Demo duration in minutes (30, 60, 120, etc.)
+    // Machine-readable build log: set to "ndjson" to also write build.ndjson
+    // in output_dir alongside the Tauri progress/result window events.
+    pub log_format: Option<String>,
+    // Symmetric key used to obfuscate the post-compile license trailer (see
+    // `stamp_license`). Baked into the wrapper so it can decrypt the
+    // trailer at runtime; omit to leave the trailer in plain JSON.
+    pub license_trailer_key: Option<String>,
+    // Base64 Ed25519 public key baked into the wrapper so it can verify the
+    // license server's signed validation responses. Omit to fall back to
+    // the old unauthenticated "status == valid" trust model.
+    pub license_public_key: Option<String>,
+    // Custom CA bundle (PEM) the wrapper trusts in addition to requiring
+    // HTTPS for license validation. Omit to use the system trust store.
+    pub ca_cert_pem: Option<String>,
+    // Hex SHA-256 fingerprint of the license server's leaf certificate.
+    // When set, the wrapper aborts validation unless the server presents
+    // exactly this certificate, guarding against CA-level MITM/downgrade.
+    pub pinned_cert_sha256: Option<String>,
+    // Policy for the pre-compile dependency install step. Defaults to
+    // `Pinned` (honor requirements.txt's exact versions) when omitted.
+    pub dependency_mode: Option<DependencyResolutionMode>,
+    // pkg target triples to build in the Node.js pipeline, e.g.
+    // ["node18-win-x64", "node18-macos-arm64", "node18-linux-x64"]. Omit to
+    // build a single target, matching past behavior: node18-win-x64 on a
+    // Windows host and node18-linux-x64 on any other host.
+    pub targets: Option<Vec<String>>,
+    // Build-time version string embedded in the generated bootstrap so the
+    // self-updater (see `updater_endpoint`) has something to compare update
+    // manifests against. Defaults to "0.0.0".
+    pub app_version: Option<String>,
+    // Update-manifest endpoint the generated bootstrap polls after license
+    // validation succeeds, expecting JSON `{version, url, signature}`. Omit
+    // to skip the self-updater entirely.
+    pub updater_endpoint: Option<String>,
+    // Base64 Ed25519 public key the bootstrap uses to verify a manifest's
+    // `signature` before staging its download - same field shape as
+    // `license_public_key`. A manifest that doesn't verify is always
+    // discarded, so a compromised endpoint can't push an unsigned update.
+    pub updater_public_key: Option<String>,
+    // Which tool packages the Node.js bootstrap into a standalone
+    // executable. Defaults to `Pkg` (the long-standing behavior); `Deno` and
+    // `Bun` produce a genuinely self-contained binary with no downloaded
+    // Node runtime.
+    pub bundler: Option<Bundler>,
+    // Days a previously-verified license response stays trusted from a
+    // local cache if the license server can't be reached. 0 (default) keeps
+    // the old strict behavior: any failed network call exits immediately.
+    pub offline_grace_days: Option<u32>,
+    // Docker image used to cross-compile when `target_os` differs from the
+    // host OS (Nuitka itself never cross-compiles). Only consulted for a
+    // Windows target built from Linux/macOS; defaults to
+    // `DEFAULT_CROSS_COMPILE_IMAGE` when omitted.
+    pub cross_compile_image: Option<String>,
+}
+
+/// Tool used to package the Node.js bootstrap into a standalone executable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bundler {
+    /// `npx pkg` - downloads a prebuilt Node binary and snapshots the app
+    /// into it. The long-standing default; kept for backward compatibility.
+    Pkg,
+    /// `deno compile` - bundles script and runtime into one native binary.
+    Deno,
+    /// `bun build --compile` - same idea, via the Bun runtime.
+    Bun,
+}
+
+impl Default for Bundler {
+    fn default() -> Self {
+        Bundler::Pkg
+    }
+}
+
+/// Resolution policy for the pre-compile dependency install step, mirroring
+/// how lockfile-respecting installers distinguish "sync to lock" from
+/// "upgrade everything".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyResolutionMode {
+    /// Honor the exact versions already locked in requirements.txt.
+    Pinned,
+    /// Ignore locked versions and fetch the newest compatible release.
+    UpgradeAll,
+}
+
+impl Default for DependencyResolutionMode {
+    fn default() -> Self {
+        DependencyResolutionMode::Pinned
+    }
 }
 
 /// Progress event sent to frontend during compilation
@@ -55,6 +163,29 @@ pub struct CompilationResult {
     pub error_message: Option<String>,
 }
 
+/// Outcome of packaging a single target triple within a multi-target
+/// `run_nodejs_compilation` run.
+#[derive(Clone, Serialize, Debug)]
+pub struct NodeTargetResult {
+    pub target: String,
+    pub success: bool,
+    pub output_path: Option<String>,
+    /// The Node.js runtime version `pkg` fetched/embedded for this target,
+    /// when that could be determined (only applicable to the `pkg`
+    /// bundler - `deno`/`bun` ship their own runtime, not a fetched one).
+    pub node_version: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// Full result of [`run_nodejs_compilation`]: one outcome per requested
+/// target triple, so a caller building for several OS/arch pairs at once
+/// can tell exactly which ones succeeded.
+#[derive(Clone, Serialize, Debug)]
+pub struct NodeCompilationReport {
+    pub job_id: String,
+    pub results: Vec<NodeTargetResult>,
+}
+
 /// Project structure scan result
 #[derive(Clone, Serialize, Debug)]
 pub struct ProjectStructure {
@@ -67,6 +198,7 @@ pub struct ProjectStructure {
     pub env_keys: Vec<String>,
     pub has_frontend: bool,
     pub frontend_framework: Option<String>,
+    pub venv: Option<toolchain::VenvInfo>,
 }
 
 /// Frontend framework detection result
@@ -78,6 +210,180 @@ pub struct FrontendInfo {
     pub build_command: String,
 }
 
+/// A three-way feature switch used by a saved [`BuildProfile`]: force the
+/// feature on or off, or leave it to whatever a fresh `detect_*` scan finds
+/// when the profile is loaded.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Toggle {
+    On,
+    Off,
+    Auto,
+}
+
+impl Toggle {
+    fn from_bool(value: Option<bool>) -> Self {
+        match value {
+            Some(true) => Toggle::On,
+            Some(false) => Toggle::Off,
+            None => Toggle::Auto,
+        }
+    }
+
+    fn resolve(self, detected: bool) -> bool {
+        match self {
+            Toggle::On => true,
+            Toggle::Off => false,
+            Toggle::Auto => detected,
+        }
+    }
+}
+
+/// A reusable, committable build configuration for a project, persisted as
+/// `codevault.toml`. Saved from a [`StartCompileRequest`] and reconstructed
+/// into one by `load_profile`, filling any `Toggle::Auto` field from a
+/// fresh project scan instead of re-asking the user every time.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildProfile {
+    pub entry_file: String,
+    pub output_name: Option<String>,
+    pub output_dir: Option<String>,
+    pub license_key: Option<String>,
+    pub server_url: Option<String>,
+    pub onefile: Toggle,
+    pub console: Toggle,
+    pub icon_path: Option<String>,
+    pub include_packages: Option<Vec<String>>,
+    pub exclude_packages: Option<Vec<String>>,
+    pub include_data_dirs: Option<Vec<String>>,
+    pub include_data_files: Option<Vec<String>>,
+    // Only the keys baked in last time are remembered, not the values -
+    // those come from the project's own .env at load time.
+    pub env_keys: Vec<String>,
+    pub install_requirements: Toggle,
+    pub requirements_path: Option<String>,
+    pub frontend: Toggle,
+    pub frontend_dir: Option<String>,
+    pub create_launcher: Toggle,
+    pub bundle_requirements: Toggle,
+    pub split_frontend: Toggle,
+    pub demo_mode: Toggle,
+    pub demo_duration_minutes: Option<u32>,
+    pub log_format: Option<String>,
+}
+
+/// Write `request` as a `codevault.toml` build profile under
+/// `project_path`, so it can be committed to source control and replayed
+/// with `load_profile` instead of re-supplying every option by hand.
+#[tauri::command]
+pub fn save_profile(project_path: String, request: StartCompileRequest) -> Result<String, String> {
+    let path = std::path::Path::new(&project_path);
+    let env_keys = request.env_values
+        .as_ref()
+        .map(|values| values.keys().cloned().collect())
+        .unwrap_or_default();
+
+    let profile = BuildProfile {
+        entry_file: request.entry_file,
+        output_name: request.output_name,
+        output_dir: request.output_dir,
+        license_key: request.license_key,
+        server_url: request.server_url,
+        onefile: Toggle::from_bool(request.onefile),
+        console: Toggle::from_bool(request.console),
+        icon_path: request.icon_path,
+        include_packages: request.include_packages,
+        exclude_packages: request.exclude_packages,
+        include_data_dirs: request.include_data_dirs,
+        include_data_files: request.include_data_files,
+        env_keys,
+        install_requirements: Toggle::from_bool(request.install_requirements),
+        requirements_path: request.requirements_path,
+        frontend: Toggle::from_bool(request.build_frontend),
+        frontend_dir: request.frontend_dir,
+        create_launcher: Toggle::from_bool(request.create_launcher),
+        bundle_requirements: Toggle::from_bool(request.bundle_requirements),
+        split_frontend: Toggle::from_bool(request.split_frontend),
+        demo_mode: Toggle::from_bool(request.demo_mode),
+        demo_duration_minutes: request.demo_duration_minutes,
+        log_format: request.log_format,
+    };
+
+    let toml = toml::to_string_pretty(&profile).map_err(|e| format!("Failed to serialize profile: {}", e))?;
+    let profile_path = path.join("codevault.toml");
+    std::fs::write(&profile_path, toml).map_err(|e| format!("Failed to write {}: {}", profile_path.display(), e))?;
+
+    Ok(profile_path.to_string_lossy().to_string())
+}
+
+/// Load `codevault.toml` from `project_path` and reconstruct a
+/// [`StartCompileRequest`], resolving any `Toggle::Auto` field from a fresh
+/// `detect_*` scan of the project.
+#[tauri::command]
+pub fn load_profile(project_path: String) -> Result<StartCompileRequest, String> {
+    let path = std::path::Path::new(&project_path);
+    let profile_path = path.join("codevault.toml");
+
+    let toml = std::fs::read_to_string(&profile_path)
+        .map_err(|e| format!("Failed to read {}: {}", profile_path.display(), e))?;
+    let profile: BuildProfile = toml::from_str(&toml)
+        .map_err(|e| format!("Failed to parse {}: {}", profile_path.display(), e))?;
+
+    let detected_frontend = detect_frontend_framework(path);
+    let has_requirements = profile.requirements_path.as_deref()
+        .map(|p| path.join(p).exists())
+        .unwrap_or_else(|| path.join("requirements.txt").exists());
+    let env_values = parse_env_file(path);
+
+    Ok(StartCompileRequest {
+        project_path,
+        entry_file: profile.entry_file,
+        output_name: profile.output_name,
+        output_dir: profile.output_dir,
+        license_key: profile.license_key,
+        server_url: profile.server_url,
+        onefile: Some(profile.onefile.resolve(true)),
+        console: Some(profile.console.resolve(false)),
+        icon_path: profile.icon_path,
+        include_packages: profile.include_packages,
+        exclude_packages: profile.exclude_packages,
+        include_data_dirs: profile.include_data_dirs,
+        include_data_files: profile.include_data_files,
+        env_values: if profile.env_keys.is_empty() {
+            None
+        } else {
+            Some(env_values.into_iter().filter(|(k, _)| profile.env_keys.contains(k)).collect())
+        },
+        install_requirements: Some(profile.install_requirements.resolve(has_requirements)),
+        requirements_path: profile.requirements_path,
+        extra_index_urls: None,
+        build_frontend: Some(profile.frontend.resolve(detected_frontend.is_some())),
+        frontend_dir: profile.frontend_dir.or_else(|| detected_frontend.as_ref().map(|f| f.path.clone())),
+        create_launcher: Some(profile.create_launcher.resolve(detected_frontend.is_some())),
+        target_os: None,
+        integrity_check: None,
+        bundle_requirements: Some(profile.bundle_requirements.resolve(has_requirements)),
+        split_frontend: Some(profile.split_frontend.resolve(false)),
+        demo_mode: Some(profile.demo_mode.resolve(false)),
+        demo_duration_minutes: profile.demo_duration_minutes,
+        log_format: profile.log_format,
+        // These fields have no counterpart in codevault.toml (see
+        // `save_profile`), so callers re-supply them each run.
+        license_trailer_key: None,
+        license_public_key: None,
+        ca_cert_pem: None,
+        pinned_cert_sha256: None,
+        dependency_mode: None,
+        targets: None,
+        app_version: None,
+        updater_endpoint: None,
+        updater_public_key: None,
+        bundler: None,
+        offline_grace_days: None,
+        cross_compile_image: None,
+    })
+}
+
 /// Detect all Python packages in a project directory
 fn detect_python_packages(project_path: &std::path::Path) -> Vec<String> {
     let mut packages = Vec::new();
@@ -295,46 +601,349 @@ fn detect_frontend_in_dir(dir: &std::path::Path) -> Option<FrontendInfo> {
     None
 }
 
-/// Install requirements using pip
+/// Which launcher script flavor to emit for the host the compile is
+/// running on. The produced script still only runs on that same OS - this
+/// just picks the right format for it.
+enum LauncherTarget {
+    Windows,
+    Unix,
+}
+
+impl LauncherTarget {
+    fn for_host() -> Self {
+        if std::env::consts::OS == "windows" {
+            LauncherTarget::Windows
+        } else {
+            LauncherTarget::Unix
+        }
+    }
+}
+
+/// Resolve the platform-correct executable name for a tool invoked via
+/// `Command::new`, so call sites don't each hardcode a bare name that only
+/// happens to work on the developer's own OS (e.g. `npm` is `npm.cmd` on
+/// Windows, and `pip` is usually `pip3` on Unix).
+fn resolve_tool(tool: &str) -> String {
+    match (tool, std::env::consts::OS) {
+        ("npm", "windows") => "npm.cmd".to_string(),
+        ("npx", "windows") => "npx.cmd".to_string(),
+        ("pip", "windows") => "pip".to_string(),
+        ("pip", _) => "pip3".to_string(),
+        ("python", "windows") => "python".to_string(),
+        ("python", _) => "python3".to_string(),
+        (other, _) => other.to_string(),
+    }
+}
+
+/// Error wrapper carrying which build stage failed, so a `CompilationResult`
+/// can name it instead of surfacing an opaque message. `install_requirements`
+/// and `build_frontend_project` below have no prerequisites on each other -
+/// only the Nuitka compile that follows depends on both - so they run as two
+/// concurrent tasks rather than a general stage-graph scheduler.
+struct StageError {
+    stage: &'static str,
+    message: String,
+}
+
+impl StageError {
+    fn new(stage: &'static str, message: impl Into<String>) -> Self {
+        Self { stage, message: message.into() }
+    }
+
+    fn into_message(self) -> String {
+        format!("[{}] {}", self.stage, self.message)
+    }
+}
+
+/// Recognized phases of a Nuitka compile, in the order they occur, each
+/// anchored to a percentage range: scanning 0-30%, C codegen 30-70%,
+/// backend compile 70-95%, onefile packaging 95-100%.
+#[derive(Clone, Copy)]
+enum NuitkaStage {
+    Init,
+    Pass1,
+    Pass2,
+    Pass3,
+    Codegen,
+    BackendCompile,
+    OnefilePackaging,
+}
+
+impl NuitkaStage {
+    fn label(self) -> &'static str {
+        match self {
+            NuitkaStage::Init => "starting",
+            NuitkaStage::Pass1 => "scanning-pass-1",
+            NuitkaStage::Pass2 => "scanning-pass-2",
+            NuitkaStage::Pass3 => "scanning-pass-3",
+            NuitkaStage::Codegen => "codegen",
+            NuitkaStage::BackendCompile => "backend-compile",
+            NuitkaStage::OnefilePackaging => "onefile-packaging",
+        }
+    }
+
+    fn base_progress(self) -> u32 {
+        match self {
+            NuitkaStage::Init => 2,
+            NuitkaStage::Pass1 => 8,
+            NuitkaStage::Pass2 => 16,
+            NuitkaStage::Pass3 => 26,
+            NuitkaStage::Codegen => 30,
+            NuitkaStage::BackendCompile => 80,
+            NuitkaStage::OnefilePackaging => 97,
+        }
+    }
+}
+
+/// Streaming progress tracker fed one Nuitka stdout/stderr line at a time,
+/// turning its phase markers into a monotonic, weighted percentage instead
+/// of the fixed increments Nuitka output was previously mapped to.
+struct NuitkaProgressTracker {
+    stage: NuitkaStage,
+    modules_seen: u32,
+    modules_total: Option<u32>,
+    last_progress: u32,
+}
+
+impl NuitkaProgressTracker {
+    fn new() -> Self {
+        Self {
+            stage: NuitkaStage::Init,
+            modules_seen: 0,
+            modules_total: None,
+            last_progress: 0,
+        }
+    }
+
+    /// Feed one output line, returning the progress percentage and stage
+    /// label to report for it.
+    fn feed(&mut self, line: &str) -> (u32, &'static str) {
+        if line.contains("PASS 1") {
+            self.stage = NuitkaStage::Pass1;
+        } else if line.contains("PASS 2") {
+            self.stage = NuitkaStage::Pass2;
+        } else if line.contains("PASS 3") {
+            self.stage = NuitkaStage::Pass3;
+        } else if line.contains("Compiling module") || line.contains("Compiling:") {
+            self.stage = NuitkaStage::Codegen;
+        } else if contains_any_ci(line, &["c compiler", "gcc", "cl.exe", "clang", "linking"]) {
+            self.stage = NuitkaStage::BackendCompile;
+        } else if line.to_lowercase().contains("onefile") {
+            self.stage = NuitkaStage::OnefilePackaging;
+        }
+
+        if let Some((seen, total)) = parse_module_counter(line) {
+            self.modules_seen = seen;
+            self.modules_total = Some(total);
+        } else if matches!(self.stage, NuitkaStage::Codegen) {
+            self.modules_seen += 1;
+        }
+
+        let progress = match self.stage {
+            NuitkaStage::Codegen => {
+                let fraction = match self.modules_total {
+                    Some(total) if total > 0 => (self.modules_seen as f32 / total as f32).min(1.0),
+                    _ => (self.modules_seen as f32 / 50.0).min(1.0),
+                };
+                30 + (fraction * 40.0) as u32
+            }
+            other => other.base_progress(),
+        };
+        // Never report less than an earlier line did.
+        let progress = progress.max(self.last_progress);
+        self.last_progress = progress;
+
+        (progress, self.stage.label())
+    }
+}
+
+/// Case-insensitive substring check against any of `needles`.
+fn contains_any_ci(line: &str, needles: &[&str]) -> bool {
+    let lower = line.to_lowercase();
+    needles.iter().any(|n| lower.contains(n))
+}
+
+/// Recognize Nuitka's "Compiling X of N" / "(X/N)" style progress lines
+/// to interpolate within the codegen phase instead of guessing.
+fn parse_module_counter(line: &str) -> Option<(u32, u32)> {
+    if let Some(idx) = line.find(" of ") {
+        let before = &line[..idx];
+        let after = &line[idx + 4..];
+        let seen: String = before.chars().rev().take_while(|c| c.is_ascii_digit()).collect::<String>().chars().rev().collect();
+        let total: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if let (Ok(seen_n), Ok(total_n)) = (seen.parse::<u32>(), total.parse::<u32>()) {
+            if total_n > 0 {
+                return Some((seen_n, total_n));
+            }
+        }
+    }
+
+    if let Some(idx) = line.find('/') {
+        let before = &line[..idx];
+        let after = &line[idx + 1..];
+        let seen: String = before.chars().rev().take_while(|c| c.is_ascii_digit()).collect::<String>().chars().rev().collect();
+        let total: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if let (Ok(seen_n), Ok(total_n)) = (seen.parse::<u32>(), total.parse::<u32>()) {
+            if total_n > 0 {
+                return Some((seen_n, total_n));
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse `CODEVAULT_AUTH_TOKENS` (format `token@host;token@host`) into a
+/// host -> token map, following Deno's `auth_tokens` env-sourced lookup.
+fn parse_auth_tokens() -> std::collections::HashMap<String, String> {
+    std::env::var("CODEVAULT_AUTH_TOKENS")
+        .unwrap_or_default()
+        .split(';')
+        .filter_map(|entry| entry.trim().rsplit_once('@'))
+        .map(|(token, host)| (host.to_string(), token.to_string()))
+        .collect()
+}
+
+/// Rewrite `https://<host>/...` to `https://<token>@<host>/...` when a
+/// token is configured for that host, so pip can authenticate against a
+/// private index without the caller having to embed the credential.
+fn authenticate_index_url(url: &str, tokens: &std::collections::HashMap<String, String>) -> String {
+    for (host, token) in tokens {
+        if let Some(rest) = url.strip_prefix("https://") {
+            if rest == *host || rest.starts_with(&format!("{}/", host)) {
+                return format!("https://{}@{}", token, rest);
+            }
+        }
+    }
+    url.to_string()
+}
+
+/// Replace every configured auth token with `***` so a pip error/log line
+/// that happens to echo an index URL never leaks the credential.
+fn scrub_tokens(text: &str, tokens: &std::collections::HashMap<String, String>) -> String {
+    let mut scrubbed = text.to_string();
+    for token in tokens.values() {
+        scrubbed = scrubbed.replace(token, "***");
+    }
+    scrubbed
+}
+
+/// Create (or reuse) a project-local `.venv` so dependency installation
+/// never touches the system/global Python, and Nuitka can then be pointed
+/// at the same isolated interpreter that has those dependencies installed.
+async fn ensure_isolated_venv(project_path: &std::path::Path) -> Result<PathBuf, String> {
+    if let Some(venv) = toolchain::detect_project_venv(project_path).await {
+        return Ok(PathBuf::from(venv.interpreter_path));
+    }
+
+    let system_python = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let output = Command::new(&system_python)
+        .args(["-m", "venv", ".venv"])
+        .current_dir(project_path)
+        .output()
+        .await
+        .map_err(|e| format!("Failed to create virtual environment: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to create virtual environment: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    toolchain::detect_project_venv(project_path)
+        .await
+        .map(|venv| PathBuf::from(venv.interpreter_path))
+        .ok_or_else(|| "Virtual environment created but its interpreter could not be located".to_string())
+}
+
+/// Create/reuse an isolated environment and install requirements into it,
+/// returning the environment's interpreter path so the Nuitka invocation
+/// that follows compiles against the same env these deps landed in.
 async fn install_requirements(
     project_path: &std::path::Path,
     requirements_path: &str,
+    extra_index_urls: &[String],
+    mode: DependencyResolutionMode,
     window: &tauri::Window,
     job_id: &str,
-) -> Result<(), String> {
+    cancelled: &Arc<AtomicBool>,
+    log: Option<&BuildEventLog>,
+) -> Result<PathBuf, String> {
     let req_full_path = project_path.join(requirements_path);
-    
+
     if !req_full_path.exists() {
         return Err(format!("Requirements file not found: {}", req_full_path.display()));
     }
-    
+
+    if cancelled.load(Ordering::SeqCst) {
+        return Err("cancelled".to_string());
+    }
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 3,
+        message: "Preparing isolated environment...".to_string(),
+        stage: "installing".to_string(),
+    }).ok();
+    if let Some(log) = log {
+        log.write(job_id, "installing", Some(3), "Preparing isolated environment...", "info", None);
+    }
+
+    let venv_python = ensure_isolated_venv(project_path).await?;
+
+    if cancelled.load(Ordering::SeqCst) {
+        return Err("cancelled".to_string());
+    }
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.to_string(),
         progress: 5,
         message: "Installing dependencies from requirements.txt...".to_string(),
         stage: "installing".to_string(),
     }).ok();
-    
-    let output = Command::new("pip")
-        .args(["install", "-r", requirements_path])
+    if let Some(log) = log {
+        log.write(job_id, "installing", Some(5), "Installing dependencies from requirements.txt...", "info", None);
+    }
+
+    let tokens = parse_auth_tokens();
+    let mut args = vec!["-m".to_string(), "pip".to_string(), "install".to_string(), "-r".to_string(), requirements_path.to_string()];
+    if mode == DependencyResolutionMode::UpgradeAll {
+        args.push("--upgrade".to_string());
+        args.push("--upgrade-strategy".to_string());
+        args.push("eager".to_string());
+    }
+    for index_url in extra_index_urls {
+        args.push("--extra-index-url".to_string());
+        args.push(authenticate_index_url(index_url, &tokens));
+    }
+
+    let output = Command::new(&venv_python)
+        .args(&args)
         .current_dir(project_path)
         .output()
         .await
         .map_err(|e| format!("Failed to run pip install: {}", e))?;
-    
+
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("pip install failed: {}", stderr));
+        let stderr = scrub_tokens(&String::from_utf8_lossy(&output.stderr), &tokens);
+        if let Some(log) = log {
+            log.write(job_id, "installing", None, &stderr, "error", Some("pip"));
+        }
+        return Err(format!("Dependency resolution failed: {}", stderr));
     }
-    
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.to_string(),
         progress: 10,
         message: "Dependencies installed successfully".to_string(),
         stage: "installed".to_string(),
     }).ok();
-    
-    Ok(())
+    if let Some(log) = log {
+        log.write(job_id, "installed", Some(10), "Dependencies installed successfully", "info", None);
+    }
+
+    Ok(venv_python)
 }
 
 /// Build frontend project
@@ -342,44 +951,66 @@ async fn build_frontend_project(
     frontend_path: &std::path::Path,
     window: &tauri::Window,
     job_id: &str,
+    cancelled: &Arc<AtomicBool>,
+    log: Option<&BuildEventLog>,
 ) -> Result<String, String> {
+    if cancelled.load(Ordering::SeqCst) {
+        return Err("cancelled".to_string());
+    }
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.to_string(),
         progress: 15,
         message: "Building frontend project...".to_string(),
         stage: "building-frontend".to_string(),
     }).ok();
-    
+    if let Some(log) = log {
+        log.write(job_id, "building-frontend", Some(15), "Building frontend project...", "info", None);
+    }
+
     // Run npm install first
-    let npm_install = Command::new("npm")
+    let npm_install = Command::new(resolve_tool("npm"))
         .args(["install"])
         .current_dir(frontend_path)
         .output()
         .await
         .map_err(|e| format!("Failed to run npm install: {}", e))?;
-    
+
     if !npm_install.status.success() {
         let stderr = String::from_utf8_lossy(&npm_install.stderr);
+        if let Some(log) = log {
+            log.write(job_id, "building-frontend", None, &stderr, "error", Some("npm"));
+        }
         return Err(format!("npm install failed: {}", stderr));
     }
-    
+
+    if cancelled.load(Ordering::SeqCst) {
+        return Err("cancelled".to_string());
+    }
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.to_string(),
         progress: 20,
         message: "Building frontend (npm run build)...".to_string(),
         stage: "building-frontend".to_string(),
     }).ok();
-    
+    if let Some(log) = log {
+        log.write(job_id, "building-frontend", Some(20), "Building frontend (npm run build)...", "info", None);
+    }
+
     // Run npm build
-    let npm_build = Command::new("npm")
+    let npm_build = Command::new(resolve_tool("npm"))
         .args(["run", "build"])
         .current_dir(frontend_path)
         .output()
         .await
         .map_err(|e| format!("Failed to run npm build: {}", e))?;
-    
+
     if !npm_build.status.success() {
         let stderr = String::from_utf8_lossy(&npm_build.stderr);
+        if let Some(log) = log {
+            log.write(job_id, "building-frontend", None, &stderr, "error", Some("npm"));
+        }
         return Err(format!("npm build failed: {}", stderr));
     }
     
@@ -404,6 +1035,136 @@ async fn build_frontend_project(
     Ok(dist_path.to_string_lossy().to_string())
 }
 
+/// Generate the launcher script matching the host OS - a `.bat` on
+/// Windows, a `.sh` on Linux/macOS - so `StartCompileRequest` produces a
+/// working full-stack launcher regardless of host OS.
+fn generate_launcher(
+    output_dir: &std::path::Path,
+    backend_exe: &str,
+    frontend_dist: Option<&str>,
+    output_name: &str,
+) -> Result<String, String> {
+    match LauncherTarget::for_host() {
+        LauncherTarget::Windows => generate_launcher_batch(output_dir, backend_exe, frontend_dist, output_name),
+        LauncherTarget::Unix => generate_launcher_shell(output_dir, backend_exe, frontend_dist, output_name),
+    }
+}
+
+/// Generate a POSIX shell launcher for Linux/macOS builds, mirroring
+/// `generate_launcher_batch`'s full-stack/backend-only behavior.
+fn generate_launcher_shell(
+    output_dir: &std::path::Path,
+    backend_exe: &str,
+    frontend_dist: Option<&str>,
+    output_name: &str,
+) -> Result<String, String> {
+    let open_cmd = if std::env::consts::OS == "macos" { "open" } else { "xdg-open" };
+
+    let script_content = if let Some(frontend) = frontend_dist {
+        format!(
+            r#"#!/usr/bin/env bash
+set -u
+SCRIPT_DIR="$(cd "$(dirname "${{BASH_SOURCE[0]}}")" && pwd)"
+
+echo
+echo "====================================================="
+echo "    {output_name} - Full-Stack Application"
+echo "====================================================="
+echo
+
+BACKEND_PID=""
+FRONTEND_PID=""
+
+cleanup() {{
+    echo
+    echo "Stopping application..."
+    [ -n "$BACKEND_PID" ] && kill "$BACKEND_PID" 2>/dev/null
+    [ -n "$FRONTEND_PID" ] && kill "$FRONTEND_PID" 2>/dev/null
+    echo "Application stopped. Goodbye!"
+}}
+trap cleanup EXIT INT TERM
+
+echo "[1/4] Starting backend server..."
+"$SCRIPT_DIR/{backend_exe}" &
+BACKEND_PID=$!
+
+echo "[2/4] Waiting for backend to initialize..."
+sleep 4
+
+if ! kill -0 "$BACKEND_PID" 2>/dev/null; then
+    echo "[ERROR] Backend failed to start!"
+    exit 1
+fi
+echo "      [OK] Backend server started on http://localhost:8000"
+
+echo "[3/4] Starting frontend server..."
+(cd "{frontend}" && npx serve -s . -l 3000 -n) &
+FRONTEND_PID=$!
+
+sleep 3
+
+echo "[4/4] Opening browser..."
+{open_cmd} "http://localhost:3000" >/dev/null 2>&1 || true
+
+echo
+echo "====================================================="
+echo "  {output_name} is now running!"
+echo "====================================================="
+echo
+echo "  Backend:  http://localhost:8000"
+echo "  Frontend: http://localhost:3000"
+echo
+echo "  Press Ctrl+C to STOP the application..."
+echo "====================================================="
+echo
+
+wait "$BACKEND_PID"
+"#,
+            output_name = output_name,
+            backend_exe = backend_exe,
+            frontend = frontend,
+            open_cmd = open_cmd,
+        )
+    } else {
+        format!(
+            r#"#!/usr/bin/env bash
+set -u
+SCRIPT_DIR="$(cd "$(dirname "${{BASH_SOURCE[0]}}")" && pwd)"
+
+echo
+echo "====================================================="
+echo "    {output_name} - Starting Application"
+echo "====================================================="
+echo
+
+echo "Starting {output_name}..."
+"$SCRIPT_DIR/{backend_exe}"
+
+echo
+echo "Application has stopped."
+"#,
+            output_name = output_name,
+            backend_exe = backend_exe,
+        )
+    };
+
+    let script_path = output_dir.join(format!("{}_launcher.sh", output_name));
+    std::fs::write(&script_path, script_content)
+        .map_err(|e| format!("Failed to create launcher: {}", e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(&script_path) {
+            let mut perms = metadata.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms).ok();
+        }
+    }
+
+    Ok(script_path.to_string_lossy().to_string())
+}
+
 /// Generate launcher batch file for full-stack applications
 /// Creates a comprehensive launcher that starts backend and frontend, opens browser, and handles shutdown
 fn generate_launcher_batch(
@@ -559,6 +1320,60 @@ fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<()
     Ok(())
 }
 
+/// Recursively collect every regular file under `dir` into `out`.
+fn collect_files_recursive(dir: &std::path::Path, out: &mut Vec<PathBuf>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_files_recursive(&path, out);
+            } else {
+                out.push(path);
+            }
+        }
+    }
+}
+
+/// Hex SHA-256 of a file's contents.
+fn sha256_file(path: &std::path::Path) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Build a `{relative_path: sha256}` integrity manifest over every file
+/// passed via `--include-data-dir`/`--include-data-files`, keyed by the
+/// same relative path it lands at inside the bundle (both flags are
+/// emitted as `dest == source`, so the runtime layout mirrors these).
+fn build_integrity_manifest(
+    project_path: &std::path::Path,
+    data_dirs: &[String],
+    data_files: &[String],
+) -> Result<std::collections::BTreeMap<String, String>, String> {
+    let mut manifest = std::collections::BTreeMap::new();
+
+    for dir in data_dirs {
+        let abs_dir = project_path.join(dir);
+        let mut files = Vec::new();
+        collect_files_recursive(&abs_dir, &mut files);
+        for file in files {
+            let rel = file.strip_prefix(project_path).unwrap_or(&file);
+            let key = rel.to_string_lossy().replace('\\', "/");
+            manifest.insert(key, sha256_file(&file)?);
+        }
+    }
+
+    for file in data_files {
+        let abs_file = project_path.join(file);
+        if abs_file.exists() {
+            manifest.insert(file.replace('\\', "/"), sha256_file(&abs_file)?);
+        }
+    }
+
+    Ok(manifest)
+}
 
 /// Inject environment variables into the entry file
 fn inject_env_values(
@@ -660,13 +1475,95 @@ _lw_install_deps()
     Ok(())
 }
 
-fn inject_license_wrapper(
-    project_path: &std::path::Path,
-    entry_file: &str,
-    license_key: &str,
-    server_url: &str,
+/// 12-byte magic marking the end of a license trailer appended by
+/// [`stamp_license`] - chosen to be unambiguous when scanning backwards
+/// from EOF, same idea as Deno's `standalone.rs` trailer on compiled exes.
+const LICENSE_TRAILER_MAGIC: &[u8; 12] = b"CODEVAULT\0\0\0";
+
+/// Expand `key` into a keystream of `len` bytes by hashing `key || counter`
+/// with SHA-256 and concatenating blocks - a simple symmetric stream
+/// cipher (XOR'd against the plaintext) used to keep the trailer's license
+/// key/server URL from sitting in the binary as plain JSON. This isn't a
+/// substitute for the signature verification and TLS pinning the license
+/// server itself should apply, just obfuscation for the stamped blob.
+fn trailer_keystream(key: &[u8], len: usize) -> Vec<u8> {
+    use sha2::{Digest, Sha256};
+    let mut out = Vec::with_capacity(len);
+    let mut counter: u64 = 0;
+    while out.len() < len {
+        let mut hasher = Sha256::new();
+        hasher.update(key);
+        hasher.update(counter.to_le_bytes());
+        out.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    out.truncate(len);
+    out
+}
+
+fn trailer_xor(data: &[u8], key: &str) -> Vec<u8> {
+    let keystream = trailer_keystream(key.as_bytes(), data.len());
+    data.iter().zip(keystream.iter()).map(|(b, k)| b ^ k).collect()
+}
+
+/// Append a `[config_bytes][u64 LE length][12-byte magic]` trailer to the
+/// compiled executable at `exe_path`, so a single base binary produced by
+/// `run_nuitka_compilation` can be re-stamped with a different license key
+/// and server URL per customer without recompiling. If `encryption_key` is
+/// set, `config_bytes` is XOR'd with its keystream first; the wrapper
+/// baked into the entry file at compile time must have been given the
+/// same key so it can decrypt the trailer at runtime.
+#[tauri::command]
+pub fn stamp_license(
+    exe_path: String,
+    license_key: String,
+    server_url: String,
+    demo_mode: Option<bool>,
+    demo_duration_minutes: Option<u32>,
+    encryption_key: Option<String>,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&exe_path);
+    if !path.exists() {
+        return Err(format!("Executable not found: {}", exe_path));
+    }
+
+    let config = serde_json::json!({
+        "license_key": license_key,
+        "server_url": server_url,
+        "demo_mode": demo_mode.unwrap_or(false),
+        "demo_duration_minutes": demo_duration_minutes.unwrap_or(60),
+    });
+    let config_bytes = serde_json::to_vec(&config)
+        .map_err(|e| format!("Failed to serialize license config: {}", e))?;
+    let config_bytes = match &encryption_key {
+        Some(key) => trailer_xor(&config_bytes, key),
+        None => config_bytes,
+    };
+
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open executable for stamping: {}", e))?;
+
+    file.write_all(&config_bytes).map_err(|e| e.to_string())?;
+    file.write_all(&(config_bytes.len() as u64).to_le_bytes()).map_err(|e| e.to_string())?;
+    file.write_all(LICENSE_TRAILER_MAGIC).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+fn inject_license_wrapper(
+    project_path: &std::path::Path,
+    entry_file: &str,
+    license_key: &str,
+    server_url: &str,
     demo_mode: bool,
     demo_duration_minutes: u32,
+    trailer_key: Option<&str>,
+    public_key: Option<&str>,
+    ca_cert_pem: Option<&str>,
+    pinned_cert_sha256: Option<&str>,
+    integrity_check: bool,
 ) -> Result<(), String> {
     let entry_path = project_path.join(entry_file);
     
@@ -693,6 +1590,10 @@ fn inject_license_wrapper(
 
     
     // License wrapper code with grace period security
+    let trailer_key_literal = trailer_key.unwrap_or("");
+    let public_key_literal = public_key.unwrap_or("");
+    let ca_cert_pem_literal = ca_cert_pem.unwrap_or("");
+    let pinned_cert_sha256_literal = pinned_cert_sha256.unwrap_or("");
     let wrapper_code = format!(r##"# ============ LICENSE WRAPPER - DO NOT REMOVE ============
 import sys as _lw_sys
 import os as _lw_os
@@ -700,11 +1601,67 @@ import hashlib as _lw_hash
 import json as _lw_json
 import time as _lw_time
 import platform as _lw_platform
+import ssl as _lw_ssl
+import http.client as _lw_http_client
+import urllib.parse as _lw_urlparse
 from pathlib import Path as _lw_Path
 
 # Grace period: 24 hours (in seconds)
 _LW_GRACE_PERIOD = 24 * 60 * 60
 
+# Magic trailing a stamp_license() blob appended to the compiled exe - see
+# `LICENSE_TRAILER_MAGIC` in compiler.rs. Layout from EOF backwards:
+# [12-byte magic][u64 LE config length][config bytes, maybe XOR'd].
+_LW_TRAILER_MAGIC = b"CODEVAULT\0\0\0"
+_LW_TRAILER_KEY = "{trailer_key}"
+
+# Base64 Ed25519 public key used to verify signed license-server responses.
+# Empty means no signature is required (unauthenticated trust model).
+_LW_PUBLIC_KEY = "{public_key}"
+
+# Custom CA bundle trusted instead of the system store when set (empty means
+# the system store), and the pinned SHA-256 fingerprint of the server's leaf
+# certificate.
+_LW_CA_CERT_PEM = """{ca_cert_pem}"""
+_LW_PINNED_CERT_SHA256 = "{pinned_cert_sha256}"
+
+def _lw_trailer_keystream(key: bytes, length: int) -> bytes:
+    """Same SHA-256 counter-mode keystream as trailer_keystream() in Rust."""
+    out = bytearray()
+    counter = 0
+    while len(out) < length:
+        out.extend(_lw_hash.sha256(key + counter.to_bytes(8, 'little')).digest())
+        counter += 1
+    return bytes(out[:length])
+
+def _lw_read_trailer():
+    """Read a stamp_license() config trailer from our own executable, if any."""
+    try:
+        exe_path = _lw_sys.executable if getattr(_lw_sys, 'frozen', False) else _lw_sys.argv[0]
+        with open(exe_path, 'rb') as f:
+            f.seek(0, _lw_os.SEEK_END)
+            size = f.tell()
+            if size < 20:
+                return None
+            f.seek(size - 12)
+            if f.read(12) != _LW_TRAILER_MAGIC:
+                return None
+            f.seek(size - 20)
+            length = int.from_bytes(f.read(8), 'little')
+            if length <= 0 or length > size - 20:
+                return None
+            f.seek(size - 20 - length)
+            blob = f.read(length)
+            if _LW_TRAILER_KEY:
+                blob = bytes(b ^ k for b, k in zip(blob, _lw_trailer_keystream(_LW_TRAILER_KEY.encode(), len(blob))))
+            return _lw_json.loads(blob.decode('utf-8'))
+    except Exception:
+        return None
+
+# Config baked in at compile time, overridden by a stamp_license() trailer
+# when present so one base binary can be re-stamped per customer.
+_LW_TRAILER_CONFIG = _lw_read_trailer() or {{}}
+
 def _lw_get_hwid():
     """Generate hardware ID."""
     try:
@@ -731,84 +1688,173 @@ def _lw_load_cache():
         pass
     return None
 
-def _lw_save_cache(valid: bool):
-    """Save license validation result to cache."""
+def _lw_save_cache(valid: bool, expiry: int = 0, hwid: str = "", nonce: str = "", timestamp: int = 0, signature: str = ""):
+    """Save a license validation result to cache, including the signed
+    fields needed to re-verify it later - see `_lw_check_grace_period`."""
     try:
         cache_path = _lw_get_cache_path()
         with open(cache_path, 'w') as f:
             _lw_json.dump({{
                 'license_key': '{license_key}',
                 'last_validated': int(_lw_time.time()),
+                'issued_at': int(_lw_time.time()),
                 'valid': valid,
-                'hwid': _lw_get_hwid()
+                'expiry': expiry,
+                'hwid': hwid,
+                'nonce': nonce,
+                'timestamp': timestamp,
+                'signature': signature
             }}, f)
     except:
         pass
 
-def _lw_check_grace_period():
-    """Check if we're within the offline grace period."""
+def _lw_check_grace_period(license_key: str, hwid: str):
+    """Fall back to a previously cached, signed validation response when
+    the license server can't be reached. Re-verifies the cached signature
+    (so a tampered cache file is worthless), rejects a cache that doesn't
+    match this machine, rejects a system clock set earlier than the
+    cache's issue time (rollback attack), and rejects a cache past its
+    grace window."""
     cache = _lw_load_cache()
-    if cache and cache.get('valid'):
-        last_validated = cache.get('last_validated', 0)
-        elapsed = int(_lw_time.time()) - last_validated
-        if elapsed < _LW_GRACE_PERIOD:
-            remaining = (_LW_GRACE_PERIOD - elapsed) // 3600
-            print(f"[Offline Mode] Using cached validation ({{remaining}}h remaining)")
+    if not cache or not cache.get('valid'):
+        return False
+    if cache.get('hwid') != hwid:
+        print("[!] Cached license does not match this machine.")
+        return False
+    expiry = cache.get('expiry', 0)
+    canonical = f"{{license_key}}|{{hwid}}|{{cache.get('nonce', '')}}|{{cache.get('timestamp', 0)}}|{{expiry}}"
+    if not _lw_verify_signature(canonical, cache.get('signature', '')):
+        print("[!] Cached license failed signature verification.")
+        return False
+    now = int(_lw_time.time())
+    issued_at = cache.get('issued_at', 0)
+    if now < issued_at:
+        print("[!] System clock is earlier than the cached license - refusing to trust it.")
+        return False
+    if expiry:
+        if now < expiry:
+            remaining = (expiry - now) // 3600
+            print(f"[Offline Mode] Using cached validation ({{remaining}}h until expiry)")
             return True
         else:
-            print("[!] Offline grace period expired. Please connect to the internet.")
+            print("[!] Cached license has expired. Please connect to the internet.")
             return False
-    return False
+    # No server-signed expiry (unsigned/legacy response) - fall back to a
+    # fixed offline window since we can't trust a longer self-reported one.
+    elapsed = now - issued_at
+    if elapsed < _LW_GRACE_PERIOD:
+        remaining = (_LW_GRACE_PERIOD - elapsed) // 3600
+        print(f"[Offline Mode] Using cached validation ({{remaining}}h remaining)")
+        return True
+    else:
+        print("[!] Offline grace period expired. Please connect to the internet.")
+        return False
+
+def _lw_verify_signature(payload: str, signature_b64: str) -> bool:
+    """Verify `signature_b64` over `payload` against the baked Ed25519 public key."""
+    if not _LW_PUBLIC_KEY:
+        return True  # No key baked in - fall back to the unauthenticated trust model
+    try:
+        import base64
+        from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PublicKey
+        from cryptography.exceptions import InvalidSignature
+        public_key = Ed25519PublicKey.from_public_bytes(base64.b64decode(_LW_PUBLIC_KEY))
+        public_key.verify(base64.b64decode(signature_b64), payload.encode('utf-8'))
+        return True
+    except InvalidSignature:
+        return False
+    except Exception as e:
+        print(f"[!] Signature verification error: {{e}}")
+        return False
+
+class _LwConnectionError(Exception):
+    """Raised when the license server can't be reached or fails TLS pinning."""
+    pass
+
+def _lw_build_ssl_context():
+    """Build an SSLContext trusting the baked CA PEM, falling back to the system store."""
+    if _LW_CA_CERT_PEM.strip():
+        return _lw_ssl.create_default_context(cadata=_LW_CA_CERT_PEM)
+    return _lw_ssl.create_default_context()
+
+def _lw_https_post(url: str, payload: bytes) -> dict:
+    """POST `payload` to `url` over HTTPS, pinning the leaf cert if configured."""
+    parsed = _lw_urlparse.urlsplit(url)
+    if parsed.scheme != 'https':
+        raise _LwConnectionError("License server URL must use HTTPS")
+
+    conn = _lw_http_client.HTTPSConnection(
+        parsed.hostname, parsed.port or 443, timeout=15, context=_lw_build_ssl_context()
+    )
+    try:
+        conn.connect()
+        if _LW_PINNED_CERT_SHA256:
+            der = conn.sock.getpeercert(binary_form=True)
+            fingerprint = _lw_hash.sha256(der).hexdigest()
+            if fingerprint.lower() != _LW_PINNED_CERT_SHA256.lower():
+                raise _LwConnectionError("Server certificate does not match pinned fingerprint")
+
+        path = parsed.path or '/'
+        if parsed.query:
+            path += '?' + parsed.query
+        conn.request('POST', path, body=payload, headers={{"Content-Type": "application/json"}})
+        resp = conn.getresponse()
+        return _lw_json.loads(resp.read().decode('utf-8'))
+    except (OSError, _lw_ssl.SSLError, _lw_http_client.HTTPException) as e:
+        raise _LwConnectionError(str(e))
+    finally:
+        conn.close()
 
 def _lw_validate():
     """Validate license with server."""
-    LICENSE_KEY = "{license_key}"
-    SERVER_URL = "{server_url}"
-    
+    LICENSE_KEY = _LW_TRAILER_CONFIG.get('license_key', "{license_key}")
+    SERVER_URL = _LW_TRAILER_CONFIG.get('server_url', "{server_url}")
+
     # Skip validation for DEMO mode
     if LICENSE_KEY == "DEMO" or LICENSE_KEY == "":
         print("[License Wrapper] Running in DEMO mode")
         return True
-    
+
     try:
-        import urllib.request
-        import urllib.error
-        
         hwid = _lw_get_hwid()
         nonce = _lw_hash.sha256(str(_lw_time.time()).encode()).hexdigest()[:32]
-        
+        timestamp = int(_lw_time.time())
+
         payload = _lw_json.dumps({{
             "license_key": LICENSE_KEY,
             "hwid": hwid,
             "machine_name": _lw_platform.node(),
             "nonce": nonce,
-            "timestamp": int(_lw_time.time())
+            "timestamp": timestamp
         }}).encode('utf-8')
-        
-        req = urllib.request.Request(
-            SERVER_URL + "/api/v1/license/validate",
-            data=payload,
-            headers={{"Content-Type": "application/json"}}
-        )
-        
-        with urllib.request.urlopen(req, timeout=15) as resp:
-            result = _lw_json.loads(resp.read().decode('utf-8'))
-            
-            if result.get("status") == "valid":
-                print("[OK] License validated successfully")
-                _lw_save_cache(True)  # Cache successful validation
-                return True
-            else:
-                msg = result.get("message", "License invalid")
-                print(f"[ERROR] License error: {{msg}}")
-                _lw_save_cache(False)  # Clear cache on revoke
+
+        result = _lw_https_post(SERVER_URL + "/api/v1/license/validate", payload)
+
+        if result.get("status") == "valid":
+            expiry = int(result.get("expiry", 0))
+            # Challenge-response: the server must sign back the exact
+            # fields we sent plus its own expiry, so a spoofed server
+            # can't just echo status == "valid".
+            canonical = f"{{LICENSE_KEY}}|{{hwid}}|{{nonce}}|{{timestamp}}|{{expiry}}"
+            if not _lw_verify_signature(canonical, result.get("signature", "")):
+                print("[ERROR] License response failed signature verification")
+                _lw_save_cache(False)
                 input("Press Enter to exit...")
                 _lw_sys.exit(1)
-                
-    except urllib.error.URLError as e:
-        print(f"[!] Could not reach license server: {{e.reason}}")
+            print("[OK] License validated successfully")
+            _lw_save_cache(True, expiry, hwid, nonce, timestamp, result.get("signature", ""))  # Cache successful validation
+            return True
+        else:
+            msg = result.get("message", "License invalid")
+            print(f"[ERROR] License error: {{msg}}")
+            _lw_save_cache(False)  # Clear cache on revoke
+            input("Press Enter to exit...")
+            _lw_sys.exit(1)
+
+    except _LwConnectionError as e:
+        print(f"[!] Could not reach license server: {{e}}")
         # Check grace period - only allow if we have a recent valid cache
-        if _lw_check_grace_period():
+        if _lw_check_grace_period(LICENSE_KEY, hwid):
             return True
         else:
             print("[ERROR] Cannot verify license. Please ensure the license server is reachable.")
@@ -823,7 +1869,7 @@ def _lw_validate():
 _lw_validate()
 # ============ END LICENSE WRAPPER ============
 
-"##, license_key = license_key, server_url = server_url);
+"##, license_key = license_key, server_url = server_url, trailer_key = trailer_key_literal, public_key = public_key_literal, ca_cert_pem = ca_cert_pem_literal, pinned_cert_sha256 = pinned_cert_sha256_literal);
     
     // Generate demo mode code if enabled
     let demo_code = if demo_mode && demo_duration_minutes > 0 {
@@ -835,8 +1881,8 @@ from pathlib import Path as _demo_Path
 
 def _lw_check_demo():
     '''Check if demo period has expired.'''
-    DEMO_DURATION_SECONDS = {} * 60  # {} minutes
-    
+    DEMO_DURATION_SECONDS = _LW_TRAILER_CONFIG.get('demo_duration_minutes', {}) * 60  # {} minutes unless re-stamped
+
     # Get demo marker file path
     appdata = _lw_os.getenv('LOCALAPPDATA', _lw_os.path.expanduser('~'))
     demo_dir = _demo_Path(appdata) / '.license_wrapper'
@@ -879,9 +1925,69 @@ _lw_check_demo()
     } else {
         String::new()
     };
-    
+
+    // Generate integrity-check code if enabled
+    let integrity_code = if integrity_check {
+        format!(r##"
+# ============ INTEGRITY CHECK - BUNDLED DATA ============
+import hashlib as _ic_hash
+import json as _ic_json
+import os as _ic_os
+import sys as _ic_sys
+
+_IC_MANIFEST_NAME = "_integrity_manifest.json"
+
+def _ic_bundle_root():
+    '''Resolve the onefile extraction dir Nuitka unpacks bundled data into.
+
+    Nuitka onefile sets NUITKA_ONEFILE_PARENT to that temp dir (there is no
+    PyInstaller-style sys._MEIPASS); non-onefile builds ship data next to
+    the executable instead.'''
+    parent = _ic_os.environ.get('NUITKA_ONEFILE_PARENT')
+    if parent:
+        return parent
+    return _ic_os.path.dirname(_ic_os.path.abspath(_ic_sys.argv[0]))
+
+def _ic_verify():
+    '''Recompute SHA-256 of every bundled data file and compare against the
+    build-time manifest, refusing to run on any mismatch or missing file.'''
+    root = _ic_bundle_root()
+    manifest_path = _ic_os.path.join(root, _IC_MANIFEST_NAME)
+    try:
+        with open(manifest_path, 'r') as f:
+            manifest = _ic_json.load(f)
+    except Exception as e:
+        print(f"[ERROR] Could not read integrity manifest: {{e}}")
+        input("Press Enter to exit...")
+        _ic_sys.exit(1)
+
+    for rel_path, expected_hash in manifest.items():
+        abs_path = _ic_os.path.join(root, *rel_path.split('/'))
+        try:
+            hasher = _ic_hash.sha256()
+            with open(abs_path, 'rb') as f:
+                hasher.update(f.read())
+            if hasher.hexdigest() != expected_hash:
+                print(f"[ERROR] Bundled file has been tampered with: {{rel_path}}")
+                input("Press Enter to exit...")
+                _ic_sys.exit(1)
+        except OSError:
+            print(f"[ERROR] Bundled file is missing: {{rel_path}}")
+            input("Press Enter to exit...")
+            _ic_sys.exit(1)
+
+    print(f"[OK] Verified integrity of {{len(manifest)}} bundled files")
+
+_ic_verify()
+# ============ END INTEGRITY CHECK ============
+
+"##)
+    } else {
+        String::new()
+    };
+
     // Write wrapped content
-    let wrapped_content = format!("{}{}{}", wrapper_code, demo_code, original_content);
+    let wrapped_content = format!("{}{}{}{}", wrapper_code, demo_code, integrity_code, original_content);
     std::fs::write(&entry_path, wrapped_content)
         .map_err(|e| format!("Failed to write wrapped file: {}", e))?;
     
@@ -908,18 +2014,83 @@ fn restore_original_file(
         }
         println!("Restored original entry file: {}", entry_file);
     }
+
+    // Integrity manifest (if any) is build output, not project source - drop
+    // it so it doesn't linger in the project directory between compiles.
+    let manifest_path = project_path.join("_integrity_manifest.json");
+    if manifest_path.exists() {
+        let _ = std::fs::remove_file(&manifest_path);
+    }
 }
 
 
+/// Default container image for cross-compiling a Windows target from
+/// Linux/macOS - a community Nuitka+MinGW image, overridable per-request via
+/// `cross_compile_image` since there's no single canonical one.
+const DEFAULT_CROSS_COMPILE_IMAGE: &str = "batonogov/nuitka:latest";
+
+/// Where the project directory is bind-mounted inside the cross-compile
+/// container.
+const CROSS_COMPILE_MOUNT: &str = "/workspace";
+
+/// Nuitka flags whose value is a filesystem path Nuitka reads/writes
+/// directly (as opposed to `--include-data-dir=SRC=DEST`, whose `DEST` is
+/// just an in-binary arcname). An absolute value here would point outside
+/// the container, since only `project_path` is mounted in - rewrite it
+/// relative to `project_path` so it still resolves under the container's
+/// cwd, or fail clearly if it falls outside the mount entirely.
+const PATH_VALUED_FLAG_PREFIXES: &[&str] = &[
+    "--output-dir=",
+    "--windows-icon-from-ico=",
+    "--linux-icon=",
+    "--macos-app-icon=",
+];
+
+fn translate_paths_for_container(args: &[String], project_path: &std::path::Path) -> Result<Vec<String>, String> {
+    args.iter().map(|arg| {
+        for prefix in PATH_VALUED_FLAG_PREFIXES {
+            if let Some(value) = arg.strip_prefix(prefix) {
+                let value_path = std::path::Path::new(value);
+                if value_path.is_absolute() {
+                    let relative = value_path.strip_prefix(project_path).map_err(|_| {
+                        format!(
+                            "Cannot cross-compile: '{}' is outside the project directory, which is the only directory mounted into the build container.",
+                            value
+                        )
+                    })?;
+                    return Ok(format!("{}{}", prefix, relative.display()));
+                }
+                return Ok(arg.clone());
+            }
+        }
+        Ok(arg.clone())
+    }).collect()
+}
+
 /// Start a Nuitka compilation job (non-blocking)
 #[tauri::command]
 pub async fn run_nuitka_compilation(
     window: tauri::Window,
     request: StartCompileRequest,
+    registry: tauri::State<'_, JobRegistry>,
 ) -> Result<String, String> {
     let job_id = uuid::Uuid::new_v4().to_string();
+    run_compile_job(window, request, registry, job_id).await
+}
+
+/// Run one compile under `job_id`, emitting `compilation-progress`/
+/// `compilation-result` tagged with it. Shared by `run_nuitka_compilation`
+/// (fresh id per call) and `run_nuitka_compilation_watch` (same id reused
+/// across every recompile in a watch session).
+async fn run_compile_job(
+    window: tauri::Window,
+    request: StartCompileRequest,
+    registry: tauri::State<'_, JobRegistry>,
+    job_id: String,
+) -> Result<String, String> {
     let job_id_clone = job_id.clone();
-    
+    let cancelled = registry.register(&job_id);
+
     // Emit start event
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
@@ -943,9 +2114,25 @@ pub async fn run_nuitka_compilation(
             output_path: None,
             error_message: Some(error_msg.clone()),
         }).ok();
+        registry.unregister(&job_id);
         return Err(error_msg);
     }
-    
+
+    // Refuse to start if Python or Nuitka is missing or too old, rather
+    // than letting the user discover it mid-Nuitka-run.
+    let report = environment_report().await?;
+    if !report.python.installed || !report.python.meets_minimum || !report.nuitka.installed || !report.nuitka.meets_minimum {
+        let error_msg = format!("Cannot start compilation: {}", report.blocking_issues.join(" "));
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(),
+            success: false,
+            output_path: None,
+            error_message: Some(error_msg.clone()),
+        }).ok();
+        registry.unregister(&job_id);
+        return Err(error_msg);
+    }
+
     // Detect language from entry file extension
     let entry_file_lower = request.entry_file.to_lowercase();
     let is_nodejs = entry_file_lower.ends_with(".js") 
@@ -972,14 +2159,26 @@ pub async fn run_nuitka_compilation(
             output_path: None,
             error_message: Some(error_msg.clone()),
         }).ok();
-        
+
+        registry.unregister(&job_id);
         return Err(error_msg);
     }
-    
+
     // Store entry file for restoration later
     let entry_file_for_restore = request.entry_file.clone();
     let project_path_for_restore = project_path.to_path_buf();
-    
+
+    // Opt-in NDJSON event log, written next to the eventual build output so
+    // it can be attached to a bug report or consumed from a headless build.
+    let log_dir = request.output_dir.as_deref()
+        .map(|s| s.trim_matches('"').trim_matches('\''))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_path.to_path_buf());
+    let build_log = Arc::new(BuildEventLog::open(request.log_format.as_deref(), &log_dir));
+    if let Some(log) = build_log.as_ref() {
+        log.write(&job_id, "init", Some(0), "Starting compilation...", "info", None);
+    }
+
     // Inject license wrapper if license key is provided
     let license_key = request.license_key.clone().unwrap_or_else(|| "DEMO".to_string());
     let server_url = request.server_url.clone().unwrap_or_else(|| "http://localhost:8000".to_string());
@@ -1002,33 +2201,127 @@ pub async fn run_nuitka_compilation(
             stage: "injecting".to_string(),
         }).ok();
         
-        if let Err(e) = inject_license_wrapper(project_path, &request.entry_file, &license_key, &server_url, demo_mode, demo_duration) {
+        if let Err(e) = inject_license_wrapper(project_path, &request.entry_file, &license_key, &server_url, demo_mode, demo_duration, request.license_trailer_key.as_deref(), request.license_public_key.as_deref(), request.ca_cert_pem.as_deref(), request.pinned_cert_sha256.as_deref(), request.integrity_check.unwrap_or(false)) {
             window.emit("compilation-result", CompilationResult {
                 job_id: job_id.clone(),
                 success: false,
                 output_path: None,
                 error_message: Some(e.clone()),
             }).ok();
+            registry.unregister(&job_id);
             return Err(e);
         }
     }
-    
-    // Install dependencies if requested
-    if request.install_requirements.unwrap_or(false) {
-        let req_path = request.requirements_path.as_deref().unwrap_or("requirements.txt");
-        if project_path.join(req_path).exists() {
-            if let Err(e) = install_requirements(project_path, req_path, &window, &job_id).await {
+
+    // Install dependencies and build the frontend concurrently: neither
+    // stage depends on the other's output, only the Nuitka compile stage
+    // that follows depends on both, so running them serially was wasted
+    // wall-clock on full-stack projects.
+    let requirements_path = request.requirements_path.clone().unwrap_or_else(|| "requirements.txt".to_string());
+    let run_install = request.install_requirements.unwrap_or(false)
+        && project_path.join(&requirements_path).exists();
+
+    // Resolve which frontend directory (if any) to build, and whether a
+    // failure there should abort the whole compile (explicit dir) or just
+    // fall back to a backend-only build (auto-detected).
+    let explicit_frontend = request.frontend_dir.as_ref().map(|dir| project_path.join(dir)).filter(|p| p.exists());
+    let auto_frontend = if request.build_frontend.unwrap_or(false) && explicit_frontend.is_none() {
+        detect_frontend_framework(project_path).map(|info| PathBuf::from(info.path))
+    } else {
+        None
+    };
+    let frontend_is_explicit = explicit_frontend.is_some();
+    let frontend_path = explicit_frontend.or(auto_frontend);
+
+    // Own a copy of the project path so the stages below can move it into
+    // their spawned (`'static`) tasks instead of borrowing it.
+    let project_path_owned = project_path.to_path_buf();
+
+    let extra_index_urls = request.extra_index_urls.clone().unwrap_or_default();
+    let dependency_mode = request.dependency_mode.unwrap_or_default();
+
+    let install_future = {
+        let window = window.clone();
+        let job_id = job_id.clone();
+        let requirements_path = requirements_path.clone();
+        let project_path = project_path_owned.clone();
+        let extra_index_urls = extra_index_urls.clone();
+        let cancelled = Arc::clone(&cancelled);
+        let build_log = Arc::clone(&build_log);
+        async move {
+            let venv_python = if run_install {
+                Some(
+                    install_requirements(&project_path, &requirements_path, &extra_index_urls, dependency_mode, &window, &job_id, &cancelled, build_log.as_ref().as_ref())
+                        .await
+                        .map_err(|e| StageError::new("installing dependencies", e))?,
+                )
+            } else {
+                None
+            };
+            Ok::<Option<PathBuf>, StageError>(venv_python)
+        }
+    };
+
+    let frontend_future = {
+        let window = window.clone();
+        let job_id = job_id.clone();
+        let cancelled = Arc::clone(&cancelled);
+        let build_log = Arc::clone(&build_log);
+        async move {
+            let dist = if let Some(ref frontend_path) = frontend_path {
+                match build_frontend_project(frontend_path, &window, &job_id, &cancelled, build_log.as_ref().as_ref()).await {
+                    Ok(dist_path) => Some(dist_path),
+                    Err(e) if frontend_is_explicit => return Err(StageError::new("building frontend", e)),
+                    Err(e) => {
+                        // Auto-detected frontend: log a warning and continue backend-only.
+                        window.emit("compilation-progress", CompilationProgress {
+                            job_id: job_id.clone(),
+                            progress: 25,
+                            message: format!("Frontend build failed (continuing with backend): {}", e),
+                            stage: "warning".to_string(),
+                        }).ok();
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            Ok::<Option<String>, StageError>(dist)
+        }
+    };
+
+    let (installed_venv_python, frontend_result) = match tokio::try_join!(
+        tokio::spawn(install_future),
+        tokio::spawn(frontend_future)
+    ) {
+        Ok((install_res, frontend_res)) => match (install_res, frontend_res) {
+            (Ok(install_ok), Ok(frontend_ok)) => (install_ok, frontend_ok),
+            (Err(e), _) | (_, Err(e)) => {
+                let error_msg = e.into_message();
                 window.emit("compilation-result", CompilationResult {
                     job_id: job_id.clone(),
                     success: false,
                     output_path: None,
-                    error_message: Some(e.clone()),
+                    error_message: Some(error_msg.clone()),
                 }).ok();
-                return Err(e);
+                registry.unregister(&job_id);
+                return Err(error_msg);
             }
+        },
+        Err(join_err) => {
+            let error_msg = format!("Build stage task panicked: {}", join_err);
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(),
+                success: false,
+                output_path: None,
+                error_message: Some(error_msg.clone()),
+            }).ok();
+            registry.unregister(&job_id);
+            return Err(error_msg);
         }
-    }
-    
+    };
+    let mut frontend_dist_path: Option<String> = frontend_result;
+
     // Inject environment values if provided (bake into binary)
     if let Some(ref env_vals) = request.env_values {
         if !env_vals.is_empty() {
@@ -1048,11 +2341,12 @@ pub async fn run_nuitka_compilation(
                     output_path: None,
                     error_message: Some(e.clone()),
                 }).ok();
+                registry.unregister(&job_id);
                 return Err(e);
             }
         }
     }
-    
+
     // Bundle requirements.txt with first-run installer if requested
     if request.bundle_requirements.unwrap_or(false) {
         let req_path = request.requirements_path.as_deref().unwrap_or("requirements.txt");
@@ -1073,85 +2367,54 @@ pub async fn run_nuitka_compilation(
                     output_path: None,
                     error_message: Some(e.clone()),
                 }).ok();
+                registry.unregister(&job_id);
                 return Err(e);
             }
         }
     }
-    
-    // Build frontend if requested
-    let mut frontend_dist_path: Option<String> = None;
-    if request.build_frontend.unwrap_or(false) {
-        if let Some(ref frontend_dir) = request.frontend_dir {
-            let frontend_path = project_path.join(frontend_dir);
-            if frontend_path.exists() {
-                match build_frontend_project(&frontend_path, &window, &job_id).await {
-                    Ok(dist_path) => {
-                        frontend_dist_path = Some(dist_path);
-                    }
-                    Err(e) => {
-                        // Restore original file and return error
-                        restore_original_file(&project_path_for_restore, &entry_file_for_restore);
-                        window.emit("compilation-result", CompilationResult {
-                            job_id: job_id.clone(),
-                            success: false,
-                            output_path: None,
-                            error_message: Some(e.clone()),
-                        }).ok();
-                        return Err(e);
-                    }
-                }
-            }
-        } else {
-            // Auto-detect frontend directory
-            if let Some(frontend_info) = detect_frontend_framework(project_path) {
-                let frontend_path = std::path::Path::new(&frontend_info.path);
-                match build_frontend_project(frontend_path, &window, &job_id).await {
-                    Ok(dist_path) => {
-                        frontend_dist_path = Some(dist_path);
-                    }
-                    Err(e) => {
-                        // Log warning but continue with backend-only compilation
-                        window.emit("compilation-progress", CompilationProgress {
-                            job_id: job_id.clone(),
-                            progress: 25,
-                            message: format!("Frontend build failed (continuing with backend): {}", e),
-                            stage: "warning".to_string(),
-                        }).ok();
-                    }
-                }
-            }
-        }
-    }
-    
+
     // Build Nuitka command arguments
+    let target_os = request.target_os.clone().unwrap_or_else(|| std::env::consts::OS.to_string());
+    let exe_suffix = if target_os == "windows" { ".exe" } else { "" };
+
     let mut args = vec![
         "-m".to_string(),
         "nuitka".to_string(),
         "--standalone".to_string(),
         "--remove-output".to_string(), // Clean up build folders after compilation
     ];
-    
+
     // Add onefile option
     if request.onefile.unwrap_or(true) {
         args.push("--onefile".to_string());
     }
-    
+
     // Add console/windows mode
-    if !request.console.unwrap_or(false) {
+    if target_os == "windows" && !request.console.unwrap_or(false) {
         args.push("--windows-console-mode=disable".to_string());
     }
-    
+
+    // macOS packages as an app bundle rather than a bare binary
+    if target_os == "macos" {
+        args.push("--macos-create-app-bundle".to_string());
+    }
+
     // Add output name
     let output_name = request.output_name.clone().unwrap_or_else(|| {
         request.entry_file.replace(".py", "")
     });
-    args.push(format!("--output-filename={}.exe", output_name));
-    
-    // Add icon if provided
+    args.push(format!("--output-filename={}{}", output_name, exe_suffix));
+
+    // Add icon if provided, in the format each target platform expects
     if let Some(ref icon) = request.icon_path {
-        args.push(format!("--windows-icon-from-ico={}", icon));
+        match target_os.as_str() {
+            "windows" => args.push(format!("--windows-icon-from-ico={}", icon)),
+            "linux" => args.push(format!("--linux-icon={}", icon)),
+            "macos" => args.push(format!("--macos-app-icon={}", icon)),
+            _ => {}
+        }
     }
-    
+
     // Add output directory if specified
     if let Some(ref out_dir) = request.output_dir {
         // Handle output directory path (might also have quotes)
@@ -1219,12 +2482,31 @@ pub async fn run_nuitka_compilation(
     }
     
     // Add include-data-files for individual files
-    if let Some(ref files) = request.include_data_files {
-        for file in files {
-            args.push(format!("--include-data-files={}={}", file, file));
-        }
+    let data_files = request.include_data_files.clone().unwrap_or_default();
+    for file in &data_files {
+        args.push(format!("--include-data-files={}={}", file, file));
     }
-    
+
+    // Hash every bundled data dir/file into a manifest and bundle it
+    // alongside them, so the injected wrapper can refuse to run if the
+    // shipped artifact's data was swapped out after the build.
+    if request.integrity_check.unwrap_or(false) {
+        let manifest = build_integrity_manifest(project_path, &data_dirs, &data_files)?;
+        let manifest_json = serde_json::to_string(&manifest)
+            .map_err(|e| format!("Failed to serialize integrity manifest: {}", e))?;
+        let manifest_path = project_path.join("_integrity_manifest.json");
+        std::fs::write(&manifest_path, &manifest_json)
+            .map_err(|e| format!("Failed to write integrity manifest: {}", e))?;
+        args.push("--include-data-files=_integrity_manifest.json=_integrity_manifest.json".to_string());
+
+        window.emit("compilation-progress", CompilationProgress {
+            job_id: job_id.clone(),
+            progress: 7,
+            message: format!("Hashed {} files for integrity manifest", manifest.len()),
+            stage: "hashing-integrity".to_string(),
+        }).ok();
+    }
+
     // Bundle requirements.txt if requested
     if request.bundle_requirements.unwrap_or(false) {
         let req_path = request.requirements_path.as_deref().unwrap_or("requirements.txt");
@@ -1280,55 +2562,210 @@ pub async fn run_nuitka_compilation(
         stage: "compiling".to_string(),
     }).ok();
     
-    // Spawn Nuitka process using tokio (non-blocking)
-    let mut child = Command::new("python")
-        .args(&args)
-        .current_dir(project_path)
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| {
-            // Enhanced error message
-            let error_msg = format!("Failed to start Nuitka in '{}': {}. Make sure Python and Nuitka are installed.", project_path.display(), e);
+    // Spawn Nuitka process using tokio (non-blocking). On Unix it gets its
+    // own process group (group id == pid) so cancellation can signal the
+    // whole tree Nuitka spawns (gcc/clang/cl.exe) rather than just itself.
+    #[cfg(unix)]
+    use std::os::unix::process::CommandExt;
+
+    // Prefer the interpreter the install stage just resolved/created, then
+    // fall back to any other project-local venv, then the global one, so
+    // Nuitka compiles against the environment that actually has the
+    // project's dependencies installed rather than bundling the wrong ones.
+    let python_path = match installed_venv_python {
+        Some(path) => path.to_string_lossy().to_string(),
+        None => match toolchain::detect_project_venv(project_path).await {
+            Some(venv) => venv.interpreter_path,
+            None => toolchain::preferred_path_or_bare(ToolKind::Python).await,
+        },
+    };
+
+    // Nuitka can't cross-compile on its own, so a `target_os` that differs
+    // from the host OS runs inside a container that already has the right
+    // toolchain (MinGW/Wine), with the project directory bind-mounted - a
+    // live mount, not a copy, so whatever Nuitka writes under the project
+    // directory is already on the host filesystem once the container exits.
+    let host_os = std::env::consts::OS;
+    let cross_compiling = target_os != host_os;
+
+    let (program, spawn_args, working_dir) = if cross_compiling {
+        if target_os != "windows" {
+            let error_msg = format!(
+                "Cannot cross-compile for '{}' from {}: only Windows targets can be cross-compiled (via Docker). Build natively on a {} machine instead.",
+                target_os, host_os, target_os
+            );
             window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: false,
-                output_path: None,
-                error_message: Some(error_msg.clone()),
+                job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
             }).ok();
-            println!("Error spawning process: {}", error_msg);
-            error_msg
-        })?;
-    
-    // Read stderr asynchronously (Nuitka outputs progress to stderr)
-    if let Some(stderr) = child.stderr.take() {
-        let reader = BufReader::new(stderr);
-        let mut lines = reader.lines();
-        let mut progress: u32 = 10;
-        
-        while let Ok(Some(line_text)) = lines.next_line().await {
-            // Parse Nuitka output for progress
-            let stage = if line_text.contains("Nuitka:INFO:") {
-                progress = (progress + 2).min(90);
-                "processing"
-            } else if line_text.contains("Nuitka:WARNING:") {
-                "warning"
-            } else if line_text.contains("Nuitka:ERROR:") {
-                "error"
+            registry.unregister(&job_id);
+            return Err(error_msg);
+        }
+
+        if !docker_available().await {
+            let error_msg = if mingw_toolchain_present().await {
+                "Cannot cross-compile for Windows: a MinGW toolchain is on PATH, but this build system only cross-compiles through Docker. Install Docker (or build natively on Windows).".to_string()
             } else {
-                progress = (progress + 1).min(90);
-                "compiling"
+                "Cannot cross-compile for Windows: neither Docker nor a MinGW cross toolchain (windres/gcc) was found. Install Docker Desktop to cross-compile from here, or build natively on a Windows machine.".to_string()
             };
-            
-            window.emit("compilation-progress", CompilationProgress {
-                job_id: job_id.clone(),
-                progress,
-                message: line_text,
-                stage: stage.to_string(),
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
             }).ok();
+            registry.unregister(&job_id);
+            return Err(error_msg);
         }
-    }
-    
+
+        let image = request.cross_compile_image.clone().unwrap_or_else(|| DEFAULT_CROSS_COMPILE_IMAGE.to_string());
+        let host_mount = project_path.canonicalize().unwrap_or_else(|_| project_path.to_path_buf());
+        let translated_args = translate_paths_for_container(&args, project_path).map_err(|e| {
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(), success: false, output_path: None, error_message: Some(e.clone()),
+            }).ok();
+            registry.unregister(&job_id);
+            e
+        })?;
+
+        window.emit("compilation-progress", CompilationProgress {
+            job_id: job_id.clone(),
+            progress: 5,
+            message: format!("Cross-compiling for Windows in container {}...", image),
+            stage: "preparing".to_string(),
+        }).ok();
+
+        let mut docker_args = vec![
+            "run".to_string(),
+            "--rm".to_string(),
+            "-v".to_string(),
+            format!("{}:{}", host_mount.display(), CROSS_COMPILE_MOUNT),
+            "-w".to_string(),
+            CROSS_COMPILE_MOUNT.to_string(),
+            image,
+            "python3".to_string(),
+        ];
+        // `args` already starts with "-m", "nuitka", ...; re-prefixed above
+        // with the container's "python3" instead of the host interpreter.
+        docker_args.extend(translated_args);
+        ("docker".to_string(), docker_args, project_path.to_path_buf())
+    } else {
+        (python_path.clone(), args.clone(), project_path.to_path_buf())
+    };
+
+    let mut command = Command::new(&program);
+    command
+        .args(&spawn_args)
+        .current_dir(&working_dir)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    #[cfg(unix)]
+    command.process_group(0);
+
+    let mut child = command.spawn().map_err(|e| {
+        // Enhanced error message
+        let error_msg = if cross_compiling {
+            format!("Failed to start Docker for cross-compilation: {}. Make sure Docker is installed and the daemon is running.", e)
+        } else {
+            format!("Failed to start Nuitka in '{}': {}. Make sure Python and Nuitka are installed.", project_path.display(), e)
+        };
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(),
+            success: false,
+            output_path: None,
+            error_message: Some(error_msg.clone()),
+        }).ok();
+        println!("Error spawning process: {}", error_msg);
+        registry.unregister(&job_id);
+        error_msg
+    })?;
+
+    if let Some(pid) = child.id() {
+        registry.track_pid(&job_id, pid);
+    }
+
+    // Read stdout and stderr asynchronously, feeding every line through the
+    // progress tracker (Nuitka mostly writes its phase banners to stderr,
+    // but per-module compile lines can land on either stream). Bail out as
+    // soon as cancellation is signalled instead of waiting for the streams
+    // to close on their own.
+    let mut tracker = NuitkaProgressTracker::new();
+
+    if let Some(stdout) = child.stdout.take() {
+        let reader = BufReader::new(stdout);
+        let mut lines = reader.lines();
+
+        while let Ok(Some(line_text)) = lines.next_line().await {
+            if cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+            let (progress, stage) = tracker.feed(&line_text);
+            if let Some(log) = build_log.as_ref() {
+                log.write(&job_id, stage, Some(progress), &line_text, "info", Some("stdout"));
+            }
+            window.emit("compilation-progress", CompilationProgress {
+                job_id: job_id.clone(),
+                progress,
+                message: line_text,
+                stage: stage.to_string(),
+            }).ok();
+        }
+    }
+
+    if !cancelled.load(Ordering::SeqCst) {
+        if let Some(stderr) = child.stderr.take() {
+            let reader = BufReader::new(stderr);
+            let mut lines = reader.lines();
+
+            while let Ok(Some(line_text)) = lines.next_line().await {
+                if cancelled.load(Ordering::SeqCst) {
+                    break;
+                }
+                let stage = if line_text.contains("Nuitka:WARNING:") {
+                    "warning"
+                } else if line_text.contains("Nuitka:ERROR:") {
+                    "error"
+                } else {
+                    let (progress, stage) = tracker.feed(&line_text);
+                    if let Some(log) = build_log.as_ref() {
+                        log.write(&job_id, stage, Some(progress), &line_text, "info", Some("stderr"));
+                    }
+                    window.emit("compilation-progress", CompilationProgress {
+                        job_id: job_id.clone(),
+                        progress,
+                        message: line_text.clone(),
+                        stage: stage.to_string(),
+                    }).ok();
+                    continue;
+                };
+
+                let (progress, _) = tracker.feed(&line_text);
+                let level = if stage == "error" { "error" } else { "warning" };
+                if let Some(log) = build_log.as_ref() {
+                    log.write(&job_id, stage, Some(progress), &line_text, level, Some("stderr"));
+                }
+                window.emit("compilation-progress", CompilationProgress {
+                    job_id: job_id.clone(),
+                    progress,
+                    message: line_text,
+                    stage: stage.to_string(),
+                }).ok();
+            }
+        }
+    }
+
+    if cancelled.load(Ordering::SeqCst) {
+        let _ = child.kill().await;
+        registry.unregister(&job_id);
+        if let Some(log) = build_log.as_ref() {
+            log.write(&job_id, "cancelled", None, "cancelled", "error", None);
+        }
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(),
+            success: false,
+            output_path: None,
+            error_message: Some("cancelled".to_string()),
+        }).ok();
+        restore_original_file(&project_path_for_restore, &entry_file_for_restore);
+        return Err("cancelled".to_string());
+    }
+
     // Wait for process to complete (async)
     let result = match child.wait().await {
         Ok(status) => {
@@ -1340,7 +2777,7 @@ pub async fn run_nuitka_compilation(
                     .unwrap_or(project_path_str);
                 
                 let output_path = PathBuf::from(base_path_clean)
-                    .join(format!("{}.exe", output_name))
+                    .join(format!("{}{}", output_name, exe_suffix))
                     .to_string_lossy()
                     .to_string();
                 
@@ -1389,9 +2826,9 @@ pub async fn run_nuitka_compilation(
                 
                 let launcher_path = if request.create_launcher.unwrap_or(false) || frontend_dist_path.is_some() {
                     let output_dir_path = PathBuf::from(base_path_clean);
-                    let backend_exe_name = format!("{}.exe", output_name);
-                    
-                    match generate_launcher_batch(
+                    let backend_exe_name = format!("{}{}", output_name, exe_suffix);
+
+                    match generate_launcher(
                         &output_dir_path,
                         &backend_exe_name,
                         launcher_frontend_path,
@@ -1425,61 +2862,223 @@ pub async fn run_nuitka_compilation(
                     stage: "complete".to_string(),
                 }).ok();
                 
+                if let Some(log) = build_log.as_ref() {
+                    log.write(&job_id, "complete", Some(100), "Compilation completed successfully!", "info", None);
+                }
                 window.emit("compilation-result", CompilationResult {
                     job_id: job_id.clone(),
                     success: true,
                     output_path: Some(final_output),
                     error_message: None,
                 }).ok();
-                
+
                 Ok(job_id_clone)
             } else {
+                if let Some(log) = build_log.as_ref() {
+                    log.write(&job_id, "failed", None, "Compilation failed", "error", None);
+                }
                 window.emit("compilation-result", CompilationResult {
                     job_id: job_id.clone(),
                     success: false,
                     output_path: None,
                     error_message: Some("Compilation failed".to_string()),
                 }).ok();
-                
+
                 Err("Compilation failed".to_string())
             }
         }
         Err(e) => {
+            if let Some(log) = build_log.as_ref() {
+                log.write(&job_id, "failed", None, &e.to_string(), "error", None);
+            }
             window.emit("compilation-result", CompilationResult {
                 job_id: job_id.clone(),
                 success: false,
                 output_path: None,
                 error_message: Some(e.to_string()),
             }).ok();
-            
+
             Err(e.to_string())
         }
     };
     
     // Always restore the original entry file after compilation
     restore_original_file(&project_path_for_restore, &entry_file_for_restore);
-    
+    registry.unregister(&job_id);
+
     result
 }
 
+/// Debounce window between the first detected `.py` change and the
+/// recompile it triggers, so a burst of saves only rebuilds once.
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Recursively collect `(path, mtime)` for every `.py` file under `dir`
+/// into `out`, skipping directories that never hold project source.
+fn scan_py_mtimes(dir: &std::path::Path, out: &mut std::collections::HashMap<PathBuf, std::time::SystemTime>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                if matches!(name, "__pycache__" | ".git" | "node_modules" | "venv" | ".venv" | "dist" | "build") {
+                    continue;
+                }
+                scan_py_mtimes(&path, out);
+            } else if path.extension().and_then(|e| e.to_str()) == Some("py") {
+                let stem_has_backup_prefix = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("_backup_"));
+                if stem_has_backup_prefix {
+                    continue;
+                }
+                if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                    out.insert(path, modified);
+                }
+            }
+        }
+    }
+}
+
+/// Start a Nuitka compile, then keep recompiling whenever a `.py` file
+/// under the project changes until the returned `watch_id` is cancelled
+/// via `cancel_compile`. Modeled on Deno's `file_watcher`: poll-and-debounce
+/// rather than a native FS watcher, so no extra dependency is required.
+///
+/// Every recompile in the session - including the first - is tagged with
+/// the same `watch_id`, so the frontend can follow one logical watch
+/// session across many `compilation-progress`/`compilation-result` events
+/// instead of reconciling a fresh job id per build.
+#[tauri::command]
+pub async fn run_nuitka_compilation_watch(
+    window: tauri::Window,
+    request: StartCompileRequest,
+    registry: tauri::State<'_, JobRegistry>,
+) -> Result<String, String> {
+    let watch_id = uuid::Uuid::new_v4().to_string();
+
+    let project_path_str = request.project_path.trim_matches('"').trim_matches('\'').to_string();
+    let project_path = PathBuf::from(project_path_str);
+    let entry_file = request.entry_file.clone();
+
+    // Run the first build synchronously so the caller's awaited result
+    // reflects it, exactly like a plain `run_nuitka_compilation` call.
+    let first_result = run_compile_job(window.clone(), request.clone(), registry, watch_id.clone()).await;
+
+    let app_handle = window.app_handle().clone();
+    tauri::async_runtime::spawn(async move {
+        let mut known = std::collections::HashMap::new();
+        scan_py_mtimes(&project_path, &mut known);
+
+        loop {
+            // Re-register between builds so `cancel_compile(watch_id)` has
+            // something to find while we're idle, not just mid-compile -
+            // `run_compile_job` unregisters `watch_id` as soon as each
+            // build finishes.
+            let cancelled = app_handle.state::<JobRegistry>().register(&watch_id);
+            tokio::time::sleep(WATCH_DEBOUNCE).await;
+            if cancelled.load(Ordering::SeqCst) {
+                app_handle.state::<JobRegistry>().unregister(&watch_id);
+                break;
+            }
+
+            let mut current = std::collections::HashMap::new();
+            scan_py_mtimes(&project_path, &mut current);
+            if current == known {
+                continue;
+            }
+            // Let the burst settle: keep re-scanning until a full debounce
+            // window passes with no further change.
+            loop {
+                tokio::time::sleep(WATCH_DEBOUNCE).await;
+                let mut settled = std::collections::HashMap::new();
+                scan_py_mtimes(&project_path, &mut settled);
+                if settled == current {
+                    break;
+                }
+                current = settled;
+            }
+            known = current;
+
+            // The previous build left the entry file wrapped with the
+            // license shim - undo that before re-injecting it, or the
+            // wrapper would end up prepended twice onto an already-wrapped
+            // file.
+            restore_original_file(&project_path, &entry_file);
+
+            window.emit("compilation-progress", CompilationProgress {
+                job_id: watch_id.clone(),
+                progress: 0,
+                message: "Change detected, recompiling...".to_string(),
+                stage: "watch".to_string(),
+            }).ok();
+
+            let registry = app_handle.state::<JobRegistry>();
+            let _ = run_compile_job(window.clone(), request.clone(), registry, watch_id.clone()).await;
+
+            // `run_compile_job` itself rewrites the entry file (inject wrapper,
+            // compile, restore) and creates/deletes the `_backup_*` file, so
+            // re-snapshot mtimes *after* it finishes rather than reusing the
+            // pre-build `current` - otherwise the build's own writes look like
+            // a fresh source change and the next tick recompiles again.
+            known.clear();
+            scan_py_mtimes(&project_path, &mut known);
+        }
+    });
+
+    first_result
+}
+
 /// Check if Nuitka is installed
 #[tauri::command]
 pub async fn check_nuitka_installed() -> Result<bool, String> {
-    let output = Command::new("python")
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let output = Command::new(&python_path)
         .args(["-m", "nuitka", "--version"])
         .output()
         .await;
-    
+
     match output {
         Ok(result) => Ok(result.status.success()),
         Err(_) => Ok(false),
     }
 }
 
+/// Check whether Docker is installed and its CLI reachable, gating the
+/// cross-compile-via-container mode in [`run_nuitka_compilation`].
+#[tauri::command]
+pub async fn check_docker_installed() -> Result<bool, String> {
+    Ok(docker_available().await)
+}
+
+async fn docker_available() -> bool {
+    Command::new("docker")
+        .arg("--version")
+        .output()
+        .await
+        .map(|result| result.status.success())
+        .unwrap_or(false)
+}
+
+/// Whether a MinGW cross toolchain (the `windres`/`gcc` pair Nuitka would
+/// need to produce a Windows PE without Docker) is on PATH. Not used to
+/// actually drive a build - only to tell a Docker-less "can't cross-compile"
+/// error apart from a "you also don't have the native toolchain" one.
+async fn mingw_toolchain_present() -> bool {
+    Command::new("x86_64-w64-mingw32-windres")
+        .arg("--version")
+        .output()
+        .await
+        .map(|result| result.status.success())
+        .unwrap_or(false)
+}
+
 /// Get Nuitka version
 #[tauri::command]
 pub async fn get_nuitka_version() -> Result<String, String> {
-    let output = Command::new("python")
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let output = Command::new(&python_path)
         .args(["-m", "nuitka", "--version"])
         .output()
         .await
@@ -1559,7 +3158,9 @@ pub async fn scan_project_structure(project_path: String) -> Result<ProjectStruc
     let frontend_info = detect_frontend_framework(path);
     let has_frontend = frontend_info.is_some();
     let frontend_framework = frontend_info.map(|f| f.framework);
-    
+
+    let venv = toolchain::detect_project_venv(path).await;
+
     Ok(ProjectStructure {
         packages,
         data_dirs,
@@ -1570,6 +3171,7 @@ pub async fn scan_project_structure(project_path: String) -> Result<ProjectStruc
         env_keys,
         has_frontend,
         frontend_framework,
+        venv,
     })
 }
 
@@ -1630,7 +3232,8 @@ except Exception as e:
     sys.exit(1)
 "#, png_path, ico_path.display());
     
-    let result = Command::new("python")
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let result = Command::new(&python_path)
         .args(["-c", &python_script])
         .output()
         .await
@@ -1668,45 +3271,21 @@ pub struct NuitkaStatus {
 /// Check if Python is installed and return version info
 #[tauri::command]
 pub async fn check_python_installed() -> Result<PythonStatus, String> {
-    let output = Command::new("python")
-        .args(["--version"])
-        .output()
-        .await;
-    
-    match output {
-        Ok(result) if result.status.success() => {
-            // Python may output version to stdout OR stderr depending on version
-            let version = if !result.stdout.is_empty() {
-                String::from_utf8_lossy(&result.stdout).trim().to_string()
-            } else {
-                String::from_utf8_lossy(&result.stderr).trim().to_string()
-            };
-            
-            // Also get python path
-            let path_output = Command::new("python")
-                .args(["-c", "import sys; print(sys.executable)"])
-                .output()
-                .await
-                .ok();
-            
-            let path = path_output
-                .filter(|o| o.status.success())
-                .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
-            
-            Ok(PythonStatus { 
-                installed: true, 
-                version: Some(version.replace("Python ", "")),
-                path,
-            })
-        },
-        _ => Ok(PythonStatus { installed: false, version: None, path: None })
+    match toolchain::preferred(ToolKind::Python).await {
+        Some(tool) => Ok(PythonStatus {
+            installed: true,
+            version: Some(tool.version.replace("Python ", "")),
+            path: Some(tool.path),
+        }),
+        None => Ok(PythonStatus { installed: false, version: None, path: None }),
     }
 }
 
 /// Get detailed Nuitka status
 #[tauri::command]
 pub async fn get_nuitka_status() -> Result<NuitkaStatus, String> {
-    let output = Command::new("python")
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let output = Command::new(&python_path)
         .args(["-m", "nuitka", "--version"])
         .output()
         .await;
@@ -1729,16 +3308,134 @@ pub async fn get_nuitka_status() -> Result<NuitkaStatus, String> {
     }
 }
 
-/// Install Nuitka via pip
+// ============ PyPI release checking ============
+
+/// A PEP 440-ish version, broken into its numeric release segments and a
+/// pre-release rank/number, so two versions can be compared with ordinary
+/// tuple ordering instead of naive string comparison.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Pep440Version {
+    release: Vec<u64>,
+    pre_rank: i8, // 0 = a, 1 = b, 2 = rc/c, 3 = final release
+    pre_num: u64,
+}
+
+impl Pep440Version {
+    fn is_prerelease(&self) -> bool {
+        self.pre_rank < 3
+    }
+}
+
+/// Parse a PEP 440-ish version string, treating `a`/`b`/`c`/`rc` suffixes as
+/// ranking below the final release they precede (e.g. `2.4.0rc1` < `2.4.0`).
+fn parse_pep440(version: &str) -> Pep440Version {
+    let version = version.trim();
+    let lower = version.to_lowercase();
+
+    for marker in ["rc", "c", "b", "a"] {
+        if let Some(idx) = lower.find(marker) {
+            // Only treat it as a pre-release marker when it's immediately
+            // preceded by a digit, to avoid misreading a stray letter.
+            if idx > 0 && version.as_bytes()[idx - 1].is_ascii_digit() {
+                let (release_part, suffix) = version.split_at(idx);
+                let pre_num: u64 = suffix[marker.len()..]
+                    .chars()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect::<String>()
+                    .parse()
+                    .unwrap_or(0);
+                let pre_rank = match marker {
+                    "a" => 0,
+                    "b" => 1,
+                    _ => 2, // "rc" or "c"
+                };
+                let release = release_part.split('.').filter_map(|p| p.parse().ok()).collect();
+                return Pep440Version { release, pre_rank, pre_num };
+            }
+        }
+    }
+
+    let release = version.split('.').filter_map(|p| p.parse().ok()).collect();
+    Pep440Version { release, pre_rank: 3, pre_num: 0 }
+}
+
+/// Query PyPI's JSON API for every release key published for `package`.
+async fn fetch_pypi_releases(package: &str) -> Result<Vec<String>, String> {
+    let url = format!("https://pypi.org/pypi/{}/json", package);
+    let response = reqwest::get(&url).await.map_err(|e| format!("Failed to query PyPI for {}: {}", package, e))?;
+    if !response.status().is_success() {
+        return Err(format!("PyPI lookup failed for {} ({})", package, response.status()));
+    }
+    let body: serde_json::Value = response.json().await.map_err(|e| format!("Failed to parse PyPI response for {}: {}", package, e))?;
+    let releases = body.get("releases")
+        .and_then(|r| r.as_object())
+        .ok_or_else(|| format!("Unexpected PyPI response shape for {}", package))?;
+    Ok(releases.keys().cloned().collect())
+}
+
+/// The highest release PyPI publishes for `package`, excluding pre-releases
+/// unless `allow_prerelease` is set.
+async fn latest_pypi_version(package: &str, allow_prerelease: bool) -> Result<Option<String>, String> {
+    let releases = fetch_pypi_releases(package).await?;
+    Ok(releases
+        .into_iter()
+        .filter(|v| allow_prerelease || !parse_pep440(v).is_prerelease())
+        .max_by(|a, b| parse_pep440(a).cmp(&parse_pep440(b))))
+}
+
+/// Compares a pip package's currently installed version against the
+/// highest version PyPI currently publishes.
+#[derive(Clone, Serialize)]
+pub struct PackageUpdateInfo {
+    pub package: String,
+    pub installed_version: Option<String>,
+    pub latest_version: Option<String>,
+    pub update_available: bool,
+}
+
+/// Check PyPI for a newer release of `package` ("nuitka" or "pillow") than
+/// what's currently installed, so the UI can show whether running the
+/// installer would actually change anything before the user clicks it.
+#[tauri::command]
+pub async fn check_pypi_update(package: String, allow_prerelease: Option<bool>) -> Result<PackageUpdateInfo, String> {
+    let installed_version = match package.to_lowercase().as_str() {
+        "nuitka" => get_nuitka_status().await?.version,
+        "pillow" => pillow_status().await.1,
+        other => return Err(format!("Unknown package: {}", other)),
+    };
+
+    let latest_version = latest_pypi_version(&package, allow_prerelease.unwrap_or(false)).await?;
+
+    let update_available = match (&installed_version, &latest_version) {
+        (Some(installed), Some(latest)) => parse_pep440(latest) > parse_pep440(installed),
+        (None, Some(_)) => true,
+        _ => false,
+    };
+
+    Ok(PackageUpdateInfo { package, installed_version, latest_version, update_available })
+}
+
+/// Install Nuitka via pip. Targets a specific resolved `version` when given
+/// (e.g. the one returned by `check_pypi_update`); otherwise upgrades to
+/// whatever pip resolves as latest.
 #[tauri::command]
-pub async fn install_nuitka() -> Result<String, String> {
+pub async fn install_nuitka(version: Option<String>) -> Result<String, String> {
     // Use python -m pip to ensure we use the correct pip for the active Python
-    let output = Command::new("python")
-        .args(["-m", "pip", "install", "nuitka", "--upgrade"])
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let mut args = vec!["-m".to_string(), "pip".to_string(), "install".to_string()];
+    match &version {
+        Some(v) => args.push(format!("nuitka=={}", v)),
+        None => {
+            args.push("nuitka".to_string());
+            args.push("--upgrade".to_string());
+        }
+    }
+    let output = Command::new(&python_path)
+        .args(&args)
         .output()
         .await
         .map_err(|e| format!("Failed to run pip: {}", e))?;
-    
+
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
         Ok(format!("Nuitka installed successfully!\n{}", stdout))
@@ -1748,16 +3445,27 @@ pub async fn install_nuitka() -> Result<String, String> {
     }
 }
 
-/// Install Pillow (for PNG to ICO conversion)
+/// Install Pillow (for PNG to ICO conversion). Targets a specific resolved
+/// `version` when given; otherwise upgrades to whatever pip resolves as
+/// latest.
 #[tauri::command]
-pub async fn install_pillow() -> Result<String, String> {
+pub async fn install_pillow(version: Option<String>) -> Result<String, String> {
     // Use python -m pip to ensure we use the correct pip for the active Python
-    let output = Command::new("python")
-        .args(["-m", "pip", "install", "Pillow", "--upgrade"])
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let mut args = vec!["-m".to_string(), "pip".to_string(), "install".to_string()];
+    match &version {
+        Some(v) => args.push(format!("Pillow=={}", v)),
+        None => {
+            args.push("Pillow".to_string());
+            args.push("--upgrade".to_string());
+        }
+    }
+    let output = Command::new(&python_path)
+        .args(&args)
         .output()
         .await
         .map_err(|e| format!("Failed to run pip: {}", e))?;
-    
+
     if output.status.success() {
         Ok("Pillow installed successfully!".to_string())
     } else {
@@ -1786,33 +3494,13 @@ pub struct PkgStatus {
 /// Check if Node.js is installed and return version info
 #[tauri::command]
 pub async fn check_node_installed() -> Result<NodeStatus, String> {
-    let output = Command::new("node")
-        .args(["--version"])
-        .output()
-        .await;
-    
-    match output {
-        Ok(result) if result.status.success() => {
-            let version = String::from_utf8_lossy(&result.stdout).trim().to_string();
-            
-            // Also get node path
-            let path_output = Command::new("where")
-                .args(["node"])
-                .output()
-                .await
-                .ok();
-            
-            let path = path_output
-                .filter(|o| o.status.success())
-                .map(|o| String::from_utf8_lossy(&o.stdout).lines().next().unwrap_or("").trim().to_string());
-            
-            Ok(NodeStatus { 
-                installed: true, 
-                version: Some(version.replace("v", "")),
-                path,
-            })
-        },
-        _ => Ok(NodeStatus { installed: false, version: None, path: None })
+    match toolchain::preferred(ToolKind::Node).await {
+        Some(tool) => Ok(NodeStatus {
+            installed: true,
+            version: Some(tool.version.replace('v', "")),
+            path: Some(tool.path),
+        }),
+        None => Ok(NodeStatus { installed: false, version: None, path: None }),
     }
 }
 
@@ -1917,24 +3605,435 @@ pub async fn check_obfuscator_installed() -> Result<ObfuscatorStatus, String> {
     }
 }
 
-/// Run Node.js compilation with license protection
-/// Uses shell execution for Windows PATH resolution
+// =============================================================================
+// Android Build Prerequisites
+// =============================================================================
+
+#[derive(Clone, Serialize)]
+pub struct JavaStatus {
+    pub installed: bool,
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct AndroidSdkStatus {
+    pub installed: bool,
+    pub sdk_path: Option<String>,
+    /// Platform/build-tools versions found under the SDK root, e.g.
+    /// "platforms;android-34" - lets the UI flag a present-but-incomplete
+    /// SDK (no platforms installed) instead of just "found"/"not found".
+    pub platforms: Vec<String>,
+}
+
+/// Check if a JDK is installed. `java -version` writes to stderr rather
+/// than stdout, unlike every other version probe in this file.
 #[tauri::command]
-pub async fn run_nodejs_compilation(
-    window: tauri::Window,
-    request: StartCompileRequest,
-) -> Result<String, String> {
+pub async fn check_java_installed() -> Result<JavaStatus, String> {
+    let result = Command::new("java").arg("-version").output().await;
+
+    match result {
+        Ok(output) if output.status.success() => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let version = stderr.lines().next().map(|l| l.trim().to_string());
+            Ok(JavaStatus { installed: true, version })
+        }
+        _ => Ok(JavaStatus { installed: false, version: None }),
+    }
+}
+
+/// Check for an Android SDK install via `ANDROID_HOME`/`ANDROID_SDK_ROOT`
+/// (Gradle and Capacitor's own tooling both honor these first), falling
+/// back to the default per-OS install location if neither is set.
+#[tauri::command]
+pub async fn check_android_sdk_installed() -> Result<AndroidSdkStatus, String> {
+    let sdk_path = std::env::var("ANDROID_HOME")
+        .or_else(|_| std::env::var("ANDROID_SDK_ROOT"))
+        .ok()
+        .map(PathBuf::from)
+        .or_else(default_android_sdk_path)
+        .filter(|p| p.exists());
+
+    let Some(sdk_path) = sdk_path else {
+        return Ok(AndroidSdkStatus { installed: false, sdk_path: None, platforms: Vec::new() });
+    };
+
+    let platforms = std::fs::read_dir(sdk_path.join("platforms"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.file_name().into_string().ok())
+                .map(|name| format!("platforms;{name}"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(AndroidSdkStatus { installed: true, sdk_path: Some(sdk_path.to_string_lossy().to_string()), platforms })
+}
+
+/// Where `sdkmanager`/Android Studio put the SDK by default when the user
+/// never set `ANDROID_HOME` themselves.
+fn default_android_sdk_path() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        std::env::var("LOCALAPPDATA").ok().map(|local| PathBuf::from(local).join("Android").join("Sdk"))
+    } else if cfg!(target_os = "macos") {
+        std::env::var("HOME").ok().map(|home| PathBuf::from(home).join("Library").join("Android").join("sdk"))
+    } else {
+        std::env::var("HOME").ok().map(|home| PathBuf::from(home).join("Android").join("Sdk"))
+    }
+}
+
+/// Request to package a detected web frontend's built output as an Android
+/// APK via a Capacitor-generated Gradle project.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AndroidBuildRequest {
+    pub project_path: String,
+    /// Directory (relative to `project_path`) holding the frontend's
+    /// `package.json` and already-built `dist/`, i.e. `FrontendInfo::path`
+    /// from [`detect_frontend`].
+    pub frontend_dir: String,
+    pub app_name: Option<String>,
+    /// Reverse-DNS package id, e.g. "com.example.myapp". Required by
+    /// `cap init` on first run; ignored on subsequent runs since it's baked
+    /// into the already-scaffolded Capacitor project.
+    pub app_id: Option<String>,
+    /// Build a signed release APK instead of a debug one. Requires
+    /// `keystore_path`/`keystore_password`/`key_alias`/`key_password`.
+    pub release: Option<bool>,
+    pub keystore_path: Option<String>,
+    pub keystore_password: Option<String>,
+    pub key_alias: Option<String>,
+    pub key_password: Option<String>,
+}
+
+/// Package a detected web frontend as an Android APK: scaffold a Capacitor
+/// project around its built `dist/` if one doesn't already exist, then
+/// drive the generated Gradle wrapper to assemble a debug or signed release
+/// APK, streaming every step's output through `compilation-progress`/
+/// `compilation-result` events the same way the Nuitka/Node pipelines do.
+#[tauri::command]
+pub async fn run_android_build(window: tauri::Window, request: AndroidBuildRequest) -> Result<String, String> {
     let job_id = uuid::Uuid::new_v4().to_string();
-    let job_id_clone = job_id.clone();
-    
-    // Emit start event
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
         progress: 0,
-        message: "Starting Node.js compilation...".to_string(),
+        message: "Starting Android build...".to_string(),
         stage: "init".to_string(),
     }).ok();
-    
+
+    let frontend_path = std::path::Path::new(&request.project_path).join(&request.frontend_dir);
+    let dist_path = frontend_path.join("dist");
+
+    if !dist_path.exists() {
+        let error_msg = format!(
+            "No built frontend found at {}. Build it first (e.g. `npm run build` in '{}').",
+            dist_path.display(), request.frontend_dir
+        );
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+
+    // Fail fast with a specific diagnostic instead of letting `cap add
+    // android`/Gradle die deep into the pipeline with a cryptic error -
+    // this is the single most common Android-toolchain-init failure mode.
+    let java = check_java_installed().await?;
+    if !java.installed {
+        let error_msg = "Cannot build Android APK: no JDK found on PATH. Install a JDK 17+ (e.g. Temurin) and ensure `java` is on PATH.".to_string();
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+    let sdk = check_android_sdk_installed().await?;
+    if !sdk.installed {
+        let error_msg = "Cannot build Android APK: no Android SDK found. Install Android Studio (or just the command-line tools) and set ANDROID_HOME.".to_string();
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    } else if sdk.platforms.is_empty() {
+        let error_msg = "Cannot build Android APK: Android SDK found, but no platform packages are installed. Run `sdkmanager \"platforms;android-34\"`.".to_string();
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+    let npm = check_npm_installed().await?;
+    if !npm.installed {
+        let error_msg = "Cannot build Android APK: npm was not found. Install Node.js/npm to run the Capacitor CLI.".to_string();
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+
+    let app_name = request.app_name.as_deref().unwrap_or("CodeVault App");
+    let app_id = request.app_id.as_deref().unwrap_or("com.codevault.app");
+    let android_dir = frontend_path.join("android");
+    let gradlew = if cfg!(target_os = "windows") { "gradlew.bat" } else { "./gradlew" };
+
+    if !frontend_path.join("capacitor.config.json").exists() {
+        window.emit("compilation-progress", CompilationProgress {
+            job_id: job_id.clone(), progress: 10, message: "Initializing Capacitor project...".to_string(), stage: "scaffolding".to_string(),
+        }).ok();
+        run_packaging_shell_command(
+            &window, &job_id, &frontend_path,
+            &format!("npx -y @capacitor/cli init '{}' '{}' --web-dir dist", app_name.replace('\'', "'\\''"), app_id),
+            "cap:init", "",
+        ).await?;
+    }
+
+    if !android_dir.exists() {
+        window.emit("compilation-progress", CompilationProgress {
+            job_id: job_id.clone(), progress: 20, message: "Adding Android platform...".to_string(), stage: "scaffolding".to_string(),
+        }).ok();
+        run_packaging_shell_command(&window, &job_id, &frontend_path, "npx -y @capacitor/cli add android", "cap:add", "").await?;
+    }
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(), progress: 35, message: "Copying web assets into the Android project...".to_string(), stage: "scaffolding".to_string(),
+    }).ok();
+    run_packaging_shell_command(&window, &job_id, &frontend_path, "npx -y @capacitor/cli copy android", "cap:copy", "").await?;
+
+    let release = request.release.unwrap_or(false);
+    let gradle_task = if release { "assembleRelease" } else { "assembleDebug" };
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(), progress: 45, message: format!("Running Gradle ({})...", gradle_task), stage: "packaging".to_string(),
+    }).ok();
+
+    let mut gradle_env = String::new();
+    if release {
+        if let (Some(ks), Some(ks_pass), Some(alias), Some(key_pass)) = (
+            request.keystore_path.as_deref(), request.keystore_password.as_deref(),
+            request.key_alias.as_deref(), request.key_password.as_deref(),
+        ) {
+            gradle_env = format!(
+                "ORG_GRADLE_PROJECT_storeFile='{}' ORG_GRADLE_PROJECT_storePassword='{}' ORG_GRADLE_PROJECT_keyAlias='{}' ORG_GRADLE_PROJECT_keyPassword='{}' ",
+                ks.replace('\'', "'\\''"), ks_pass.replace('\'', "'\\''"), alias.replace('\'', "'\\''"), key_pass.replace('\'', "'\\''"),
+            );
+        } else {
+            let error_msg = "Cannot build a release APK: keystore_path/keystore_password/key_alias/key_password are all required for a signed release build.".to_string();
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+            }).ok();
+            return Err(error_msg);
+        }
+    }
+
+    run_packaging_shell_command(
+        &window, &job_id, &android_dir,
+        &format!("{}{} {}", gradle_env, gradlew, gradle_task),
+        "gradle", "",
+    ).await?;
+
+    let apk_path = android_dir
+        .join("app").join("build").join("outputs").join("apk")
+        .join(if release { "release" } else { "debug" })
+        .join(if release { "app-release.apk" } else { "app-debug.apk" });
+
+    if !apk_path.exists() {
+        let error_msg = format!("Gradle reported success but no APK was found at {}.", apk_path.display());
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(), success: false, output_path: None, error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+
+    let apk_path_str = apk_path.to_string_lossy().to_string();
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(), progress: 100, message: "Android build completed successfully!".to_string(), stage: "completed".to_string(),
+    }).ok();
+    window.emit("compilation-result", CompilationResult {
+        job_id: job_id.clone(), success: true, output_path: Some(apk_path_str.clone()), error_message: None,
+    }).ok();
+
+    Ok(apk_path_str)
+}
+
+// ============ Phase 6: Environment Doctor ============
+
+// Minimum versions the compile pipeline relies on, so an outdated tool is
+// rejected up front with a specific error instead of failing mid-Nuitka or
+// mid-pkg with a confusing message.
+const MIN_PYTHON_VERSION: (u64, u64, u64) = (3, 8, 0);
+const MIN_NUITKA_VERSION: (u64, u64, u64) = (1, 8, 0);
+const MIN_NODE_VERSION: (u64, u64, u64) = (18, 0, 0);
+
+/// Parse a `(major, minor, patch)` triple out of the first dotted numeric
+/// run in a version string (e.g. "3.11.4", "v20.11.0", "Nuitka 2.4.8").
+fn parse_version_triple(version: &str) -> Option<(u64, u64, u64)> {
+    let digits_and_dots: String = version
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+
+    let mut parts = digits_and_dots.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().unwrap_or(0);
+    let patch = parts.next().unwrap_or("0").parse().unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+/// One tool's status within the aggregated environment report.
+#[derive(Clone, Serialize)]
+pub struct ToolReport {
+    pub name: String,
+    pub installed: bool,
+    pub version: Option<String>,
+    pub path: Option<String>,
+    pub meets_minimum: bool,
+}
+
+impl ToolReport {
+    fn new(
+        name: &str,
+        installed: bool,
+        version: Option<String>,
+        path: Option<String>,
+        minimum: Option<(u64, u64, u64)>,
+    ) -> Self {
+        let meets_minimum = match (minimum, version.as_deref().and_then(parse_version_triple)) {
+            (Some(min), Some(actual)) => actual >= min,
+            (None, _) => installed,
+            (Some(_), None) => false,
+        };
+        ToolReport { name: name.to_string(), installed, version, path, meets_minimum }
+    }
+}
+
+/// Aggregated toolchain health report covering everything the Python and
+/// Node compile pipelines depend on, gathered in one pass instead of the
+/// UI polling each `check_*_installed` command separately.
+#[derive(Clone, Serialize)]
+pub struct EnvironmentReport {
+    pub python: ToolReport,
+    pub nuitka: ToolReport,
+    pub pillow: ToolReport,
+    pub node: ToolReport,
+    pub npm: ToolReport,
+    pub pkg: ToolReport,
+    pub obfuscator: ToolReport,
+    pub nsis: ToolReport,
+    pub host_os: String,
+    pub host_arch: String,
+    pub ready_to_compile: bool,
+    pub blocking_issues: Vec<String>,
+}
+
+/// Probe Pillow's version the same way `convert_png_to_ico` detects its
+/// absence, but report a version instead of just a boolean.
+async fn pillow_status() -> (bool, Option<String>) {
+    let python_path = toolchain::preferred_path_or_bare(ToolKind::Python).await;
+    let output = Command::new(&python_path)
+        .args(["-c", "import PIL; print(PIL.__version__)"])
+        .output()
+        .await;
+
+    match output {
+        Ok(result) if result.status.success() => {
+            let version = String::from_utf8_lossy(&result.stdout).trim().to_string();
+            (true, Some(version))
+        }
+        _ => (false, None),
+    }
+}
+
+/// Gather Python, Nuitka, Pillow, Node, npm, pkg, javascript-obfuscator, and
+/// NSIS status into one structured report - mirroring `tauri info`'s
+/// dependency dump - plus the host OS/arch, and flag which tools fall below
+/// the minimum version this crate's generated commands rely on. The compile
+/// commands consult this instead of each doing their own scattered boolean
+/// `installed` check; the frontend can render a one-shot readiness panel
+/// from it instead of calling every `check_*_installed` command separately.
+/// The individual probes are independent processes, so they run
+/// concurrently rather than one after another.
+#[tauri::command]
+pub async fn environment_report() -> Result<EnvironmentReport, String> {
+    let (python, nuitka, pillow_status_result, node, npm, pkg, obfuscator, nsis) = tokio::join!(
+        check_python_installed(),
+        get_nuitka_status(),
+        pillow_status(),
+        check_node_installed(),
+        check_npm_installed(),
+        check_pkg_installed(),
+        check_obfuscator_installed(),
+        check_nsis_installed(),
+    );
+
+    let python = python?;
+    let nuitka = nuitka?;
+    let (pillow_installed, pillow_version) = pillow_status_result;
+    let node = node?;
+    let npm = npm?;
+    let pkg = pkg?;
+    let obfuscator = obfuscator?;
+    let nsis = nsis?;
+
+    let python = ToolReport::new("python", python.installed, python.version, python.path, Some(MIN_PYTHON_VERSION));
+    let nuitka = ToolReport::new("nuitka", nuitka.installed, nuitka.version, None, Some(MIN_NUITKA_VERSION));
+    let pillow = ToolReport::new("pillow", pillow_installed, pillow_version, None, None);
+    let node = ToolReport::new("node", node.installed, node.version, node.path, Some(MIN_NODE_VERSION));
+    let npm = ToolReport::new("npm", npm.installed, npm.version, None, None);
+    let pkg = ToolReport::new("pkg", pkg.installed, pkg.version, None, None);
+    let obfuscator = ToolReport::new("javascript-obfuscator", obfuscator.installed, obfuscator.version, None, None);
+    let nsis = ToolReport::new("nsis", nsis.installed, nsis.version, nsis.path, None);
+    let host_os = std::env::consts::OS.to_string();
+    let host_arch = std::env::consts::ARCH.to_string();
+
+    let mut blocking_issues = Vec::new();
+    if !python.installed {
+        blocking_issues.push("Python is not installed.".to_string());
+    } else if !python.meets_minimum {
+        blocking_issues.push(format!(
+            "Python {} is too old; {}.{}.{} or newer is required.",
+            python.version.as_deref().unwrap_or("unknown"),
+            MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1, MIN_PYTHON_VERSION.2
+        ));
+    }
+    if !nuitka.installed {
+        blocking_issues.push("Nuitka is not installed.".to_string());
+    } else if !nuitka.meets_minimum {
+        blocking_issues.push(format!(
+            "Nuitka {} is too old; {}.{}.{} or newer is required.",
+            nuitka.version.as_deref().unwrap_or("unknown"),
+            MIN_NUITKA_VERSION.0, MIN_NUITKA_VERSION.1, MIN_NUITKA_VERSION.2
+        ));
+    }
+    if node.installed && !node.meets_minimum {
+        blocking_issues.push(format!(
+            "Node.js {} is too old for the node18 pkg target; {}.{}.{} or newer is required.",
+            node.version.as_deref().unwrap_or("unknown"),
+            MIN_NODE_VERSION.0, MIN_NODE_VERSION.1, MIN_NODE_VERSION.2
+        ));
+    }
+
+    let ready_to_compile = blocking_issues.is_empty();
+
+    Ok(EnvironmentReport { python, nuitka, pillow, node, npm, pkg, obfuscator, nsis, host_os, host_arch, ready_to_compile, blocking_issues })
+}
+
+/// Run Node.js compilation with license protection
+/// Uses shell execution for Windows PATH resolution
+#[tauri::command]
+pub async fn run_nodejs_compilation(
+    window: tauri::Window,
+    request: StartCompileRequest,
+) -> Result<NodeCompilationReport, String> {
+    let job_id = uuid::Uuid::new_v4().to_string();
+    let job_id_clone = job_id.clone();
+    
+    // Emit start event
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(),
+        progress: 0,
+        message: "Starting Node.js compilation...".to_string(),
+        stage: "init".to_string(),
+    }).ok();
+    
     let project_path = std::path::Path::new(&request.project_path);
     let entry_file = &request.entry_file;
     let entry_path = project_path.join(entry_file);
@@ -1949,7 +4048,21 @@ pub async fn run_nodejs_compilation(
         }).ok();
         return Err(error_msg);
     }
-    
+
+    // Refuse to start if Node.js is too old for the node18 pkg target,
+    // rather than letting the user discover it mid-pkg-run.
+    let report = environment_report().await?;
+    if report.node.installed && !report.node.meets_minimum {
+        let error_msg = format!("Cannot start compilation: {}", report.blocking_issues.join(" "));
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(),
+            success: false,
+            output_path: None,
+            error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
         progress: 10,
@@ -1983,9 +4096,10 @@ pub async fn run_nodejs_compilation(
         let npm_result = Command::new("sh")
             .args(["-c", "npm install"])
             .current_dir(project_path)
+            .env("PATH", toolchain::search_path())
             .output()
             .await;
-        
+
         match npm_result {
             Ok(output) if output.status.success() => {
                 window.emit("compilation-progress", CompilationProgress {
@@ -2044,14 +4158,26 @@ pub async fn run_nodejs_compilation(
     let license_key = request.license_key.as_deref().unwrap_or("DEMO");
     let server_url = request.server_url.as_deref().unwrap_or("http://localhost:8000");
     let api_url = format!("{}/api/v1/license/validate", server_url);
-    
+    // Base64 Ed25519 public key, same field and encoding the Python license
+    // wrapper already trusts (see `_LW_PUBLIC_KEY`/`_lw_verify_signature`).
+    // Empty means no signature is required (unauthenticated trust model).
+    let public_key = request.license_public_key.as_deref().unwrap_or("");
+    // Days a cached, signed validation response stays trusted once the
+    // server becomes unreachable. 0 keeps the old strict behavior.
+    let offline_grace_days = request.offline_grace_days.unwrap_or(0);
+
     let license_wrapper_js = format!(r#"
 const crypto = require('crypto');
 const os = require('os');
+const fs = require('fs');
+const path = require('path');
 
 // License Configuration
 const LICENSE_KEY = '{}';
 const API_URL = '{}';
+const LICENSE_PUBLIC_KEY = '{}';
+const OFFLINE_GRACE_DAYS = {};
+const OFFLINE_CACHE_PATH = path.join(os.homedir(), '.codevault', 'license-cache.json');
 
 function getHWID() {{
     try {{
@@ -2064,17 +4190,85 @@ function getHWID() {{
     }}
 }}
 
+// Verify `signatureB64` over `payload` against the baked Ed25519 public
+// key. Returns true (no-op trust) when no key is baked in.
+function verifySignature(payload, signatureB64) {{
+    if (!LICENSE_PUBLIC_KEY) {{
+        return true;
+    }}
+    try {{
+        const spkiPrefix = Buffer.from('302a300506032b6570032100', 'hex');
+        const publicKeyDer = Buffer.concat([spkiPrefix, Buffer.from(LICENSE_PUBLIC_KEY, 'base64')]);
+        const publicKey = crypto.createPublicKey({{ key: publicKeyDer, format: 'der', type: 'spki' }});
+        return crypto.verify('ed25519', Buffer.from(payload), publicKey, Buffer.from(signatureB64 || '', 'base64'));
+    }} catch (e) {{
+        console.error('[CodeVault] Signature verification error:', e.message);
+        return false;
+    }}
+}}
+
+// Persists a server-signed validation response so `tryOfflineGrace` can
+// replay it later if the server becomes unreachable.
+function cacheLicenseResponse(entry) {{
+    try {{
+        fs.mkdirSync(path.dirname(OFFLINE_CACHE_PATH), {{ recursive: true }});
+        fs.writeFileSync(OFFLINE_CACHE_PATH, JSON.stringify(entry));
+    }} catch (e) {{
+        console.error('[CodeVault] Failed to write offline license cache:', e.message);
+    }}
+}}
+
+// Falls back to a previously cached, signed validation response when the
+// license server can't be reached. Re-verifies the cached signature (so a
+// tampered cache file is worthless), rejects a cache that doesn't match
+// this machine, rejects a system clock set earlier than the cache's issue
+// time (rollback attack), and rejects a cache past its grace window.
+function tryOfflineGrace(hwid) {{
+    if (OFFLINE_GRACE_DAYS <= 0) {{
+        return false;
+    }}
+    let cached;
+    try {{
+        cached = JSON.parse(fs.readFileSync(OFFLINE_CACHE_PATH, 'utf8'));
+    }} catch (e) {{
+        console.error('[CodeVault] No cached license available for offline use');
+        return false;
+    }}
+    if (cached.hwid !== hwid) {{
+        console.error('[CodeVault] Cached license does not match this machine');
+        return false;
+    }}
+    const canonical = `${{LICENSE_KEY}}|${{cached.hwid}}|${{cached.nonce}}|${{cached.timestamp}}|${{cached.expiry}}`;
+    if (!verifySignature(canonical, cached.signature)) {{
+        console.error('[CodeVault] Cached license failed signature verification');
+        return false;
+    }}
+    const now = Date.now();
+    if (now < cached.issuedAt) {{
+        console.error('[CodeVault] System clock is earlier than the cached license - refusing to trust it');
+        return false;
+    }}
+    const validUntil = cached.issuedAt + (OFFLINE_GRACE_DAYS * 24 * 60 * 60 * 1000);
+    if (now >= validUntil) {{
+        console.error('[CodeVault] Cached license has exceeded the offline grace period');
+        return false;
+    }}
+    const daysLeft = Math.ceil((validUntil - now) / (24 * 60 * 60 * 1000));
+    console.log(`[CodeVault] Running on cached license (expires in ${{daysLeft}} days)`);
+    return true;
+}}
+
 function validateLicense() {{
     if (LICENSE_KEY === 'DEMO') {{
         console.log('[CodeVault] Running in DEMO mode');
         return Promise.resolve(true);
     }}
-    
+
     return new Promise((resolve, reject) => {{
         const hwid = getHWID();
         const nonce = crypto.randomBytes(16).toString('hex');
         const timestamp = Math.floor(Date.now() / 1000);
-        
+
         let urlObj;
         try {{
             urlObj = new URL(API_URL);
@@ -2090,7 +4284,7 @@ function validateLicense() {{
             timestamp: timestamp,
             machine_name: os.hostname()
         }});
-        
+
         const options = {{
             hostname: urlObj.hostname,
             port: urlObj.port || (urlObj.protocol === 'https:' ? 443 : 80),
@@ -2101,7 +4295,7 @@ function validateLicense() {{
                 'Content-Length': Buffer.byteLength(postData)
             }}
         }};
-        
+
         const lib = urlObj.protocol === 'http:' ? require('http') : require('https');
 
         const req = lib.request(options, (res) => {{
@@ -2111,10 +4305,33 @@ function validateLicense() {{
                 try {{
                     if (res.statusCode !== 200) {{
                         console.error(`[CodeVault] Validation failed (HTTP ${{res.statusCode}})`);
-                        process.exit(1);
+                        if (tryOfflineGrace(hwid)) {{ resolve(true); }} else {{ process.exit(1); }}
+                        return;
                     }}
                     const response = JSON.parse(body);
                     if (response.status === 'valid') {{
+                        // Challenge-response: the server must sign back the
+                        // exact fields we sent plus its own expiry and
+                        // nonce, so a spoofed server (or a MITM redirecting
+                        // API_URL) can't just echo status == "valid".
+                        if (response.nonce !== nonce) {{
+                            console.error('[CodeVault] Nonce mismatch - possible replay attack');
+                            process.exit(1);
+                        }}
+                        const expiry = response.expiry || 0;
+                        const canonical = `${{LICENSE_KEY}}|${{hwid}}|${{nonce}}|${{timestamp}}|${{expiry}}`;
+                        if (!verifySignature(canonical, response.signature)) {{
+                            console.error('[CodeVault] License response failed signature verification');
+                            process.exit(1);
+                        }}
+                        cacheLicenseResponse({{
+                            hwid: hwid,
+                            nonce: nonce,
+                            timestamp: timestamp,
+                            expiry: expiry,
+                            signature: response.signature,
+                            issuedAt: Date.now(),
+                        }});
                         resolve(true);
                     }} else {{
                         console.error('[CodeVault] License invalid:', response.message || 'Unknown error');
@@ -2126,19 +4343,19 @@ function validateLicense() {{
                 }}
             }});
         }});
-        
+
         req.on('error', (e) => {{
             console.error('[CodeVault] Connection error:', e.message);
-            process.exit(1);
+            if (tryOfflineGrace(hwid)) {{ resolve(true); }} else {{ process.exit(1); }}
         }});
-        
+
         req.write(postData);
         req.end();
     }});
 }}
 
 module.exports = validateLicense;
-"#, license_key, api_url);
+"#, license_key, api_url, public_key, offline_grace_days);
 
     // Create license wrapper file
     let wrapper_path = project_path.join("_cv_license_wrapper.js");
@@ -2153,6 +4370,156 @@ module.exports = validateLicense;
         return Err(error_msg);
     }
     
+    // Self-updater module - optional, controlled entirely by whether an
+    // endpoint/public key are baked in. Always written alongside the license
+    // wrapper (mirroring how LICENSE_KEY == "DEMO" no-ops the wrapper rather
+    // than omitting it) so the bootstrap can unconditionally `require` it.
+    let app_version = request.app_version.as_deref().unwrap_or("0.0.0");
+    let updater_endpoint = request.updater_endpoint.as_deref().unwrap_or("");
+    let updater_public_key = request.updater_public_key.as_deref().unwrap_or("");
+
+    let updater_js = format!(r#"
+const crypto = require('crypto');
+const http = require('http');
+const https = require('https');
+const os = require('os');
+const fs = require('fs');
+const path = require('path');
+const {{ spawn }} = require('child_process');
+
+const UPDATER_ENDPOINT = '{}';
+const UPDATER_PUBLIC_KEY = '{}';
+const CURRENT_VERSION = '{}';
+
+function verifyUpdateSignature(payload, signatureB64) {{
+    if (!UPDATER_PUBLIC_KEY) {{
+        return false;
+    }}
+    try {{
+        const spkiPrefix = Buffer.from('302a300506032b6570032100', 'hex');
+        const publicKeyDer = Buffer.concat([spkiPrefix, Buffer.from(UPDATER_PUBLIC_KEY, 'base64')]);
+        const publicKey = crypto.createPublicKey({{ key: publicKeyDer, format: 'der', type: 'spki' }});
+        return crypto.verify('ed25519', payload, publicKey, Buffer.from(signatureB64 || '', 'base64'));
+    }} catch (e) {{
+        console.error('[CodeVault] Update signature verification error:', e.message);
+        return false;
+    }}
+}}
+
+function compareVersions(a, b) {{
+    const pa = a.split('.').map((n) => parseInt(n, 10) || 0);
+    const pb = b.split('.').map((n) => parseInt(n, 10) || 0);
+    for (let i = 0; i < Math.max(pa.length, pb.length); i++) {{
+        const diff = (pa[i] || 0) - (pb[i] || 0);
+        if (diff !== 0) return diff;
+    }}
+    return 0;
+}}
+
+function fetchJson(url) {{
+    return new Promise((resolve, reject) => {{
+        const lib = url.startsWith('https:') ? https : http;
+        lib.get(url, (res) => {{
+            let body = '';
+            res.on('data', (chunk) => body += chunk);
+            res.on('end', () => {{
+                try {{ resolve(JSON.parse(body)); }} catch (e) {{ reject(e); }}
+            }});
+        }}).on('error', reject);
+    }});
+}}
+
+function downloadToFile(url, destPath) {{
+    return new Promise((resolve, reject) => {{
+        const lib = url.startsWith('https:') ? https : http;
+        const file = fs.createWriteStream(destPath);
+        lib.get(url, (res) => {{
+            if (res.statusCode !== 200) {{
+                reject(new Error(`Update download failed (HTTP ${{res.statusCode}})`));
+                return;
+            }}
+            res.pipe(file);
+            file.on('finish', () => file.close(resolve));
+        }}).on('error', reject);
+    }});
+}}
+
+// Spawns a detached finalizer that waits for this process to exit before
+// touching anything on disk, so the running executable is never replaced
+// out from under itself - only ever staged, then swapped in after exit.
+function scheduleSwap(execPath, stagedPath, pid) {{
+    if (process.platform === 'win32') {{
+        const script = [
+            ':wait',
+            `tasklist /fi "PID eq ${{pid}}" | find "${{pid}}" >nul`,
+            'if not errorlevel 1 (',
+            '    timeout /t 1 /nobreak >nul',
+            '    goto wait',
+            ')',
+            `move /y "${{stagedPath}}" "${{execPath}}" >nul`,
+            `start "" "${{execPath}}"`,
+            'del "%~f0"',
+        ].join('\r\n');
+        const batPath = path.join(os.tmpdir(), `cv-update-${{pid}}.bat`);
+        fs.writeFileSync(batPath, script);
+        spawn('cmd.exe', ['/c', batPath], {{ detached: true, stdio: 'ignore', windowsHide: true }}).unref();
+    }} else {{
+        const script = `while kill -0 ${{pid}} 2>/dev/null; do sleep 0.3; done; mv -f "${{stagedPath}}" "${{execPath}}"; chmod +x "${{execPath}}"`;
+        spawn('/bin/sh', ['-c', script], {{ detached: true, stdio: 'ignore' }}).unref();
+    }}
+}}
+
+// Polls UPDATER_ENDPOINT for a signed manifest `{{version, url, signature}}`
+// newer than CURRENT_VERSION and, if found, downloads and stages it next to
+// the running executable. Never fatal: a failed check, download, or a
+// manifest that doesn't verify just gets logged and skipped, since an
+// update is always optional for the current run. No-ops entirely when no
+// endpoint or public key is baked in.
+function checkForUpdate() {{
+    if (!UPDATER_ENDPOINT || !UPDATER_PUBLIC_KEY) {{
+        return Promise.resolve();
+    }}
+    return fetchJson(UPDATER_ENDPOINT).then((manifest) => {{
+        if (!manifest || !manifest.version || !manifest.url || !manifest.signature) {{
+            return;
+        }}
+        if (compareVersions(manifest.version, CURRENT_VERSION) <= 0) {{
+            return;
+        }}
+        const execPath = process.execPath;
+        const stagedPath = `${{execPath}}.update-${{manifest.version}}`;
+        return downloadToFile(manifest.url, stagedPath).then(() => {{
+            const data = fs.readFileSync(stagedPath);
+            if (!verifyUpdateSignature(data, manifest.signature)) {{
+                console.error('[CodeVault] Update signature invalid - discarding download');
+                fs.unlinkSync(stagedPath);
+                return;
+            }}
+            fs.chmodSync(stagedPath, 0o755);
+            scheduleSwap(execPath, stagedPath, process.pid);
+            console.log(`[CodeVault] Update ${{manifest.version}} staged - will apply after this run exits.`);
+        }});
+    }}).catch((e) => {{
+        console.error('[CodeVault] Update check failed:', e.message);
+    }});
+}}
+
+module.exports = checkForUpdate;
+"#, updater_endpoint, updater_public_key, app_version);
+
+    let updater_path = project_path.join("_cv_updater.js");
+    if let Err(e) = std::fs::write(&updater_path, &updater_js) {
+        std::fs::remove_file(&wrapper_path).ok();
+        let error_msg = format!("Failed to create updater module: {}", e);
+        window.emit("compilation-result", CompilationResult {
+            job_id: job_id.clone(),
+            success: false,
+            output_path: None,
+            error_message: Some(error_msg.clone()),
+        }).ok();
+        return Err(error_msg);
+    }
+
     // Create bootstrap entry file that validates license then runs main
     let bootstrap_id = uuid::Uuid::new_v4().to_string().replace("-", "")[..8].to_string();
     let bootstrap_filename = format!("_cv_bootstrap_{}.js", bootstrap_id);
@@ -2167,8 +4534,10 @@ module.exports = validateLicense;
     
     let bootstrap_content = format!(r#"
 const validateLicense = require('./_cv_license_wrapper');
+const checkForUpdate = require('./_cv_updater');
 validateLicense().then(() => {{
     console.log('[CodeVault] License verified. Starting application...');
+    checkForUpdate();
     require('{}');
 }}).catch(err => {{
     console.error('[CodeVault] Startup error:', err);
@@ -2177,8 +4546,9 @@ validateLicense().then(() => {{
 "#, entry_require_path);
 
     if let Err(e) = std::fs::write(&bootstrap_path, &bootstrap_content) {
-        // Cleanup wrapper on failure
+        // Cleanup wrapper and updater module on failure
         std::fs::remove_file(&wrapper_path).ok();
+        std::fs::remove_file(&updater_path).ok();
         let error_msg = format!("Failed to create bootstrap entry: {}", e);
         window.emit("compilation-result", CompilationResult {
             job_id: job_id.clone(),
@@ -2189,210 +4559,422 @@ validateLicense().then(() => {{
         return Err(error_msg);
     }
     
+    let bundler = request.bundler.unwrap_or_default();
+    let bundler_name = match bundler {
+        Bundler::Pkg => "pkg",
+        Bundler::Deno => "deno",
+        Bundler::Bun => "bun",
+    };
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
         progress: 25,
-        message: "License wrapper injected. Packaging with pkg...".to_string(),
+        message: format!("License wrapper injected. Packaging with {}...", bundler_name),
         stage: "packaging".to_string(),
     }).ok();
-    
+
     // Determine output path
     let output_name = request.output_name.clone().unwrap_or_else(|| {
         entry_path.file_stem()
             .map(|s| s.to_string_lossy().to_string())
             .unwrap_or_else(|| "output".to_string())
     });
-    
+
     let output_dir = request.output_dir.clone()
         .map(PathBuf::from)
         .unwrap_or_else(|| project_path.parent().unwrap_or(project_path).join("output"));
-    
+
     std::fs::create_dir_all(&output_dir).ok();
-    
-    let output_exe = output_dir.join(format!("{}.exe", output_name));
-    
-    // Build pkg command
-    // Use PowerShell on Windows for proper quoted path handling
-    // PowerShell handles paths with spaces much better than cmd
-    let output_exe_str = output_exe.to_string_lossy().to_string();
-    let entry_file_relative = format!(".\\{}", bootstrap_filename);
-    
+
+    // Targets to build. For pkg, defaults to a single target matching past
+    // behavior when the caller doesn't ask for more: node18-win-x64 on a
+    // Windows host, node18-linux-x64 on any other host. For
+    // `deno`/`bun`, a "target" is that bundler's own cross-compile triple
+    // (e.g. "x86_64-pc-windows-msvc"); pass an empty string to build for the
+    // host only.
+    let targets = match request.targets.clone() {
+        Some(t) if !t.is_empty() => t,
+        _ => vec![match bundler {
+            Bundler::Pkg if cfg!(target_os = "windows") => "node18-win-x64".to_string(),
+            Bundler::Pkg => "node18-linux-x64".to_string(),
+            Bundler::Deno | Bundler::Bun => String::new(),
+        }],
+    };
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
         progress: 40,
-        message: format!("Running: npx -y pkg {} --output {}", entry_file_relative, output_exe_str),
-        stage: "packaging".to_string(),
-    }).ok();
-    
-    // Run pkg via PowerShell for proper PATH resolution on Windows
-    // Use spawn() instead of output() for real-time streaming
-    window.emit("compilation-progress", CompilationProgress {
-        job_id: job_id.clone(),
-        progress: 45,
-        message: "Starting pkg (may download Node.js binaries on first run)...".to_string(),
+        message: format!("Starting {} for {} target(s){}...", bundler_name, targets.len(),
+            if bundler == Bundler::Pkg { " (may download Node.js binaries on first run)" } else { "" }),
         stage: "packaging".to_string(),
     }).ok();
-    
-    // Use PowerShell which handles paths with spaces properly
-    #[cfg(target_os = "windows")]
-    let mut child = match Command::new("powershell")
-        .args([
-            "-NoProfile",
-            "-Command",
-            &format!(
-                "npx -y pkg '{}' --target node18-win-x64 --output '{}'",
-                entry_file_relative,
-                output_exe_str.replace('\'', "''")  // Escape single quotes
-            )
-        ])
-        .current_dir(project_path)
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-    {
-        Ok(c) => c,
-        Err(e) => {
-            std::fs::remove_file(&wrapper_path).ok();
-            std::fs::remove_file(&bootstrap_path).ok();
-            let error_msg = format!("Failed to start pkg: {}\n\nMake sure Node.js is installed and npx is in your PATH.", e);
-            window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: false,
-                output_path: None,
-                error_message: Some(error_msg.clone()),
-            }).ok();
-            return Err(error_msg);
+
+    let mut results = Vec::with_capacity(targets.len());
+    let mut any_failed = false;
+
+    for target in &targets {
+        let exe_suffix = if target.contains("win") || (target.is_empty() && cfg!(target_os = "windows")) { ".exe" } else { "" };
+        let target_suffix = if target.is_empty() { "host".to_string() } else { target.clone() };
+        let output_exe = output_dir.join(format!("{}-{}{}", output_name, target_suffix, exe_suffix));
+
+        let result = match bundler {
+            Bundler::Pkg => run_pkg_for_target(&window, &job_id, project_path, &bootstrap_filename, target, &output_exe).await,
+            Bundler::Deno => run_deno_for_target(&window, &job_id, project_path, &bootstrap_filename, target, &output_exe).await
+                .map(|path| (path, None)),
+            Bundler::Bun => run_bun_for_target(&window, &job_id, project_path, &bootstrap_filename, target, &output_exe).await
+                .map(|path| (path, None)),
+        };
+
+        match result {
+            Ok((path, node_version)) => {
+                window.emit("compilation-result", CompilationResult {
+                    job_id: job_id.clone(),
+                    success: true,
+                    output_path: Some(path.clone()),
+                    error_message: None,
+                }).ok();
+                results.push(NodeTargetResult {
+                    target: target.clone(),
+                    success: true,
+                    output_path: Some(path),
+                    node_version,
+                    error_message: None,
+                });
+            }
+            Err(e) => {
+                window.emit("compilation-result", CompilationResult {
+                    job_id: job_id.clone(),
+                    success: false,
+                    output_path: None,
+                    error_message: Some(e.clone()),
+                }).ok();
+                any_failed = true;
+                results.push(NodeTargetResult {
+                    target: target.clone(),
+                    success: false,
+                    output_path: None,
+                    node_version: None,
+                    error_message: Some(e.clone()),
+                });
+            }
         }
+    }
+
+    // Cleanup temp files regardless of result
+    std::fs::remove_file(&wrapper_path).ok();
+    std::fs::remove_file(&updater_path).ok();
+    std::fs::remove_file(&bootstrap_path).ok();
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(),
+        progress: 100,
+        message: if any_failed { "Build finished with at least one failed target.".to_string() } else { "Build completed successfully!".to_string() },
+        stage: "completed".to_string(),
+    }).ok();
+
+    // Report per-target outcomes even when some targets failed, rather
+    // than collapsing a partially-successful multi-target build into one
+    // opaque error string - the caller needs to know which triples to
+    // retry.
+    Ok(NodeCompilationReport { job_id: job_id_clone, results })
+}
+
+/// Pick out the fetched Node.js base binary's version from `pkg`'s own
+/// stdout (it logs a line like `> Fetched base Node.js binary: node-v18.20.4-win-x64`
+/// on a cache miss), falling back to the major version baked into the
+/// target triple itself (`node18-win-x64` -> `"18"`) when pkg served the
+/// binary from its cache and printed nothing to parse.
+fn node_version_from_pkg_run(stdout_lines: &[String], target: &str) -> Option<String> {
+    stdout_lines
+        .iter()
+        .find_map(|line| {
+            let idx = line.find("node-v")?;
+            let rest = &line[idx + "node-v".len()..];
+            let version: String = rest.chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+            (!version.is_empty()).then_some(version)
+        })
+        .or_else(|| {
+            let digits: String = target.strip_prefix("node")?.chars().take_while(|c| c.is_ascii_digit()).collect();
+            (!digits.is_empty()).then_some(digits)
+        })
+}
+
+/// Run pkg once for a single target triple, streaming its output into
+/// `CompilationProgress` events and returning the produced executable path
+/// plus the Node.js runtime version `pkg` fetched for it.
+async fn run_pkg_for_target(
+    window: &tauri::Window,
+    job_id: &str,
+    project_path: &std::path::Path,
+    bootstrap_filename: &str,
+    target: &str,
+    output_exe: &std::path::Path,
+) -> Result<(String, Option<String>), String> {
+    // Use PowerShell on Windows for proper quoted path handling; PowerShell
+    // handles paths with spaces much better than cmd.
+    let output_exe_str = output_exe.to_string_lossy().to_string();
+    #[cfg(target_os = "windows")]
+    let entry_file_relative = format!(".\\{}", bootstrap_filename);
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 45,
+        message: format!("Running: npx -y pkg --target {} --output {}", target, output_exe_str),
+        stage: "packaging".to_string(),
+    }).ok();
+
+    #[cfg(target_os = "windows")]
+    let mut child = match Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            &format!(
+                "npx -y pkg '{}' --target {} --output '{}'",
+                entry_file_relative,
+                target,
+                output_exe_str.replace('\'', "''")  // Escape single quotes
+            )
+        ])
+        .current_dir(project_path)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => return Err(format!("Failed to start pkg for target {}: {}\n\nMake sure Node.js is installed and npx is in your PATH.", target, e)),
     };
-    
+
     #[cfg(not(target_os = "windows"))]
     let mut child = match Command::new("sh")
         .args([
             "-c",
             &format!(
-                "npx -y pkg '.{}' --target node18-linux-x64 --output '{}'",
+                "npx -y pkg '.{}' --target {} --output '{}'",
                 bootstrap_filename,
+                target,
                 output_exe_str.replace('\'', "'\\''")  // Escape single quotes for bash
             )
         ])
         .current_dir(project_path)
+        .env("PATH", toolchain::search_path())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .spawn()
     {
         Ok(c) => c,
-        Err(e) => {
-            std::fs::remove_file(&wrapper_path).ok();
-            std::fs::remove_file(&bootstrap_path).ok();
-            let error_msg = format!("Failed to start pkg: {}\n\nMake sure Node.js is installed.", e);
-            window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: false,
-                output_path: None,
-                error_message: Some(error_msg.clone()),
-            }).ok();
-            return Err(error_msg);
-        }
+        Err(e) => return Err(format!("Failed to start pkg for target {}: {}\n\nMake sure Node.js is installed.", target, e)),
     };
-    
+
     // Collect output while streaming progress
     let mut stdout_lines = Vec::new();
     let mut stderr_lines = Vec::new();
     let mut progress_counter = 50; // Start at 50%, increment to 90%
-    
+
     // Read stdout in real-time
     if let Some(stdout) = child.stdout.take() {
-        use tokio::io::{AsyncBufReadExt, BufReader};
         let reader = BufReader::new(stdout);
         let mut lines = reader.lines();
-        
+
         while let Ok(Some(line)) = lines.next_line().await {
             stdout_lines.push(line.clone());
-            
+
             // Emit progress with the actual log line
             progress_counter = std::cmp::min(progress_counter + 2, 90);
             window.emit("compilation-progress", CompilationProgress {
-                job_id: job_id.clone(),
+                job_id: job_id.to_string(),
                 progress: progress_counter,
-                message: format!("[pkg] {}", line),
+                message: format!("[pkg:{}] {}", target, line),
                 stage: "packaging".to_string(),
             }).ok();
         }
     }
-    
+
     // Read any remaining stderr
     if let Some(stderr) = child.stderr.take() {
-        use tokio::io::{AsyncBufReadExt, BufReader};
         let reader = BufReader::new(stderr);
         let mut lines = reader.lines();
-        
+
         while let Ok(Some(line)) = lines.next_line().await {
             stderr_lines.push(line.clone());
-            
+
             // Emit stderr as progress too (could be warnings/errors)
             window.emit("compilation-progress", CompilationProgress {
-                job_id: job_id.clone(),
+                job_id: job_id.to_string(),
                 progress: progress_counter,
-                message: format!("[pkg stderr] {}", line),
+                message: format!("[pkg:{} stderr] {}", target, line),
                 stage: "packaging".to_string(),
             }).ok();
         }
     }
-    
+
     // Wait for process to complete
     let status = child.wait().await;
-    
-    // Cleanup temp files regardless of result
-    std::fs::remove_file(&wrapper_path).ok();
-    std::fs::remove_file(&bootstrap_path).ok();
-    
+
     match status {
-        Ok(s) if s.success() => {
+        Ok(s) if s.success() => Ok((output_exe_str, node_version_from_pkg_run(&stdout_lines, target))),
+        Ok(_) => {
+            let stdout_str = stdout_lines.join("\n");
+            let stderr_str = stderr_lines.join("\n");
+            Err(format!("pkg build failed for target {}:\n{}\n{}", target, stdout_str, stderr_str))
+        },
+        Err(e) => Err(format!("Failed to wait for pkg (target {}): {}\n\nMake sure Node.js is installed and npx is in your PATH.", target, e)),
+    }
+}
+
+/// Run a packaging tool's shell command, streaming its stdout/stderr into
+/// `CompilationProgress` events tagged with `label`. Shared by the
+/// `deno`/`bun` bundlers, which - unlike pkg - are plain standalone binaries
+/// with no npx wrapper quirks, so they can share one spawn/stream/wait
+/// implementation. The Android build's Capacitor and Gradle steps use it
+/// too.
+async fn run_packaging_shell_command(
+    window: &tauri::Window,
+    job_id: &str,
+    project_path: &std::path::Path,
+    shell_command: &str,
+    label: &str,
+    output_exe_str: &str,
+) -> Result<String, String> {
+    #[cfg(target_os = "windows")]
+    let mut child = match Command::new("powershell")
+        .args(["-NoProfile", "-Command", shell_command])
+        .current_dir(project_path)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => return Err(format!("Failed to start {}: {}\n\nMake sure it's installed and on PATH.", label, e)),
+    };
+
+    #[cfg(not(target_os = "windows"))]
+    let mut child = match Command::new("sh")
+        .args(["-c", shell_command])
+        .current_dir(project_path)
+        .env("PATH", toolchain::search_path())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => return Err(format!("Failed to start {}: {}\n\nMake sure it's installed and on PATH.", label, e)),
+    };
+
+    let mut stdout_lines = Vec::new();
+    let mut stderr_lines = Vec::new();
+    let mut progress_counter = 50;
+
+    if let Some(stdout) = child.stdout.take() {
+        let reader = BufReader::new(stdout);
+        let mut lines = reader.lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            stdout_lines.push(line.clone());
+            progress_counter = std::cmp::min(progress_counter + 2, 90);
             window.emit("compilation-progress", CompilationProgress {
-                job_id: job_id.clone(),
-                progress: 100,
-                message: "Build completed successfully!".to_string(),
-                stage: "completed".to_string(),
+                job_id: job_id.to_string(),
+                progress: progress_counter,
+                message: format!("[{}] {}", label, line),
+                stage: "packaging".to_string(),
             }).ok();
-            
-            window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: true,
-                output_path: Some(output_exe.to_string_lossy().to_string()),
-                error_message: None,
+        }
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        let reader = BufReader::new(stderr);
+        let mut lines = reader.lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            stderr_lines.push(line.clone());
+            window.emit("compilation-progress", CompilationProgress {
+                job_id: job_id.to_string(),
+                progress: progress_counter,
+                message: format!("[{} stderr] {}", label, line),
+                stage: "packaging".to_string(),
             }).ok();
-            
-            Ok(job_id_clone)
-        },
+        }
+    }
+
+    match child.wait().await {
+        Ok(s) if s.success() => Ok(output_exe_str.to_string()),
         Ok(_) => {
             let stdout_str = stdout_lines.join("\n");
             let stderr_str = stderr_lines.join("\n");
-            let error_msg = format!("pkg build failed:\n{}\n{}", stdout_str, stderr_str);
-            
-            window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: false,
-                output_path: None,
-                error_message: Some(error_msg.clone()),
-            }).ok();
-            
-            Err(error_msg)
-        },
-        Err(e) => {
-            let error_msg = format!("Failed to wait for pkg: {}\n\nMake sure Node.js is installed and npx is in your PATH.", e);
-            
-            window.emit("compilation-result", CompilationResult {
-                job_id: job_id.clone(),
-                success: false,
-                output_path: None,
-                error_message: Some(error_msg.clone()),
-            }).ok();
-            
-            Err(error_msg)
+            Err(format!("{} build failed:\n{}\n{}", label, stdout_str, stderr_str))
         }
+        Err(e) => Err(format!("Failed to wait for {}: {}", label, e)),
     }
 }
 
+/// Package the bootstrap with `deno compile`, producing a standalone
+/// executable with the Deno runtime baked in - no separate Node.js install
+/// required on the target machine. `target` is a Deno target triple (e.g.
+/// "x86_64-pc-windows-msvc"); pass an empty string to build for the host.
+async fn run_deno_for_target(
+    window: &tauri::Window,
+    job_id: &str,
+    project_path: &std::path::Path,
+    bootstrap_filename: &str,
+    target: &str,
+    output_exe: &std::path::Path,
+) -> Result<String, String> {
+    let output_exe_str = output_exe.to_string_lossy().to_string();
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 45,
+        message: format!("Running: deno compile --allow-net --output {} {}", output_exe_str, bootstrap_filename),
+        stage: "packaging".to_string(),
+    }).ok();
+
+    let target_flag = if target.is_empty() {
+        String::new()
+    } else {
+        format!("--target {} ", target)
+    };
+    let shell_command = format!(
+        "deno compile --allow-net {}--output '{}' './{}'",
+        target_flag,
+        output_exe_str.replace('\'', "'\\''"),
+        bootstrap_filename,
+    );
+
+    run_packaging_shell_command(window, job_id, project_path, &shell_command, &format!("deno:{}", if target.is_empty() { "host" } else { target }), &output_exe_str).await
+}
+
+/// Package the bootstrap with `bun build --compile`, the Bun equivalent of
+/// `deno compile`. `target` is a Bun cross-compile target (e.g.
+/// "bun-windows-x64"); pass an empty string to build for the host.
+async fn run_bun_for_target(
+    window: &tauri::Window,
+    job_id: &str,
+    project_path: &std::path::Path,
+    bootstrap_filename: &str,
+    target: &str,
+    output_exe: &std::path::Path,
+) -> Result<String, String> {
+    let output_exe_str = output_exe.to_string_lossy().to_string();
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 45,
+        message: format!("Running: bun build --compile --outfile {} {}", output_exe_str, bootstrap_filename),
+        stage: "packaging".to_string(),
+    }).ok();
+
+    let target_flag = if target.is_empty() {
+        String::new()
+    } else {
+        format!("--target={} ", target)
+    };
+    let shell_command = format!(
+        "bun build './{}' --compile {}--outfile '{}'",
+        bootstrap_filename,
+        target_flag,
+        output_exe_str.replace('\'', "'\\''"),
+    );
+
+    run_packaging_shell_command(window, job_id, project_path, &shell_command, &format!("bun:{}", if target.is_empty() { "host" } else { target }), &output_exe_str).await
+}
+
 /// Request for building a professional installer
 #[derive(Debug, Deserialize)]
 pub struct InstallerBuildRequest {
@@ -2409,6 +4991,171 @@ pub struct InstallerBuildRequest {
     pub create_desktop_shortcut: Option<bool>,
     pub create_start_menu: Option<bool>,
     pub output_dir: Option<String>,
+    // Self-updater config, forwarded to the build orchestrator so it can
+    // bake the same `{version, url, signature}` update-manifest polling into
+    // the bootstrap it produces. Omit to ship without a self-updater.
+    pub updater_endpoint: Option<String>,
+    pub updater_public_key: Option<String>,
+    // Platform to package for: "windows" | "macos" | "linux". Tells the
+    // orchestrator which native format to emit (NSIS .exe, pkgbuild .pkg,
+    // or AppImage/.deb). Defaults to the host OS when omitted.
+    pub target_os: Option<String>,
+    // Icon and VERSIONINFO to embed in the produced installer executable.
+    // Only meaningful for `target_os == "windows"`; forwarded to the
+    // orchestrator as `None` (a no-op) for every other target.
+    pub resource_config: Option<ResourceConfig>,
+    // Shard/parity/encryption config for the bundled payload (see
+    // `payload_protection`). Omit to bundle the project source plainly,
+    // matching behavior before this feature existed.
+    pub payload_protection: Option<super::payload_protection::PayloadProtection>,
+    // Path to an already-compiled, standalone backend executable. When set,
+    // it's copied into the installer output's `resources/backend/`
+    // directory (see `bundle_backend_sidecar`) so the packaged app can find
+    // it at runtime without a system Python install - see
+    // `backend_manager::find_bundled_backend_sidecar`. Omit to ship without
+    // a bundled backend sidecar.
+    pub backend_sidecar_path: Option<String>,
+}
+
+/// When `config` is supplied, zip `project_path`'s contents, seal them with
+/// [`payload_protection::protect_payload`], and write the result to
+/// `<output_dir>/payload.protected.json` for the orchestrator to verify and
+/// reconstruct before it runs NSIS. Returns the written path; the caller
+/// skips this step when no config was given.
+fn seal_installer_payload(
+    project_path: &std::path::Path,
+    output_dir: &std::path::Path,
+    config: &super::payload_protection::PayloadProtection,
+) -> Result<String, String> {
+    let mut zip_bytes: Vec<u8> = Vec::new();
+    {
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(&mut zip_bytes));
+        let options = zip::write::SimpleFileOptions::default();
+        for entry in walkdir::WalkDir::new(project_path).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = path.strip_prefix(project_path).unwrap_or(path).to_string_lossy().replace('\\', "/");
+            if path.is_dir() {
+                if !name.is_empty() {
+                    writer.add_directory(name, options).map_err(|e| format!("Failed to add directory to archive: {e}"))?;
+                }
+            } else {
+                writer.start_file(name, options).map_err(|e| format!("Failed to add file to archive: {e}"))?;
+                let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                std::io::Write::write_all(&mut writer, &bytes).map_err(|e| format!("Failed to write archive entry: {e}"))?;
+            }
+        }
+        writer.finish().map_err(|e| format!("Failed to finalize archive: {e}"))?;
+    }
+
+    let protected = super::payload_protection::protect_payload(&zip_bytes, config)?;
+    std::fs::create_dir_all(output_dir).map_err(|e| format!("Failed to create output directory: {e}"))?;
+    let sealed_path = output_dir.join("payload.protected.json");
+    let json = serde_json::to_string(&protected).map_err(|e| format!("Failed to serialize protected payload: {e}"))?;
+    std::fs::write(&sealed_path, json).map_err(|e| format!("Failed to write protected payload: {e}"))?;
+
+    Ok(sealed_path.to_string_lossy().to_string())
+}
+
+/// Copy an already-compiled, standalone backend executable into the
+/// installer output's `resources/backend/` directory - the layout
+/// `backend_manager::find_bundled_backend_sidecar` looks for at runtime -
+/// and mark it executable on Unix, since that bit is easy to lose crossing
+/// a zip or network transfer. Returns the copied path.
+fn bundle_backend_sidecar(source: &std::path::Path, output_dir: &std::path::Path, target_os: &str) -> Result<String, String> {
+    if !source.exists() {
+        return Err(format!("Backend sidecar binary not found: {}", source.display()));
+    }
+
+    let exe_name = if target_os == "windows" { "backend_service.exe" } else { "backend_service" };
+    let sidecar_dir = output_dir.join("resources").join("backend");
+    std::fs::create_dir_all(&sidecar_dir).map_err(|e| format!("Failed to create sidecar directory: {e}"))?;
+    let dest = sidecar_dir.join(exe_name);
+    std::fs::copy(source, &dest).map_err(|e| format!("Failed to copy backend sidecar from {}: {e}", source.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&dest).map_err(|e| e.to_string())?.permissions();
+        perms.set_mode(perms.mode() | 0o111);
+        std::fs::set_permissions(&dest, perms).map_err(|e| e.to_string())?;
+    }
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+/// Result of [`verify_sidecar_bundled`]: whether `run_installer_build`'s
+/// backend sidecar actually made it into a built output directory, and
+/// whether it's marked executable.
+#[derive(Clone, Serialize, Debug)]
+pub struct SidecarStatus {
+    pub present: bool,
+    pub path: Option<String>,
+    pub executable: bool,
+}
+
+/// Check a built installer output directory for the bundled backend
+/// sidecar, so a broken bundle step is caught at build time instead of as a
+/// first-launch "No such file or directory" from the packaged app.
+#[tauri::command]
+pub async fn verify_sidecar_bundled(output_dir: String, target_os: Option<String>) -> Result<SidecarStatus, String> {
+    let target_os = target_os.unwrap_or_else(|| std::env::consts::OS.to_string());
+    let exe_name = if target_os == "windows" { "backend_service.exe" } else { "backend_service" };
+    let path = std::path::Path::new(&output_dir).join("resources").join("backend").join(exe_name);
+
+    if !path.exists() {
+        return Ok(SidecarStatus { present: false, path: None, executable: false });
+    }
+
+    let executable = {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::metadata(&path).map(|m| m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+        }
+        #[cfg(not(unix))]
+        {
+            true
+        }
+    };
+
+    Ok(SidecarStatus { present: true, path: Some(path.to_string_lossy().to_string()), executable })
+}
+
+/// Windows version-info and icon resources to embed into a produced NSIS
+/// installer, the same information a `winres` build script would compile
+/// into a `.rc` and link into an executable. Since installer packaging
+/// itself happens server-side in the build orchestrator (see
+/// `run_installer_build`), this crate's job is just to resolve the icon to
+/// an `.ico` and forward the VERSIONINFO fields alongside it - the
+/// orchestrator does the actual resource compile/link.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResourceConfig {
+    pub icon: PathBuf,
+    pub version: String,
+    pub product_name: String,
+    pub company_name: Option<String>,
+    pub copyright: Option<String>,
+}
+
+/// Resolve a [`ResourceConfig`] for the orchestrator payload: `None` for
+/// every non-Windows target (icon/VERSIONINFO embedding is a Windows PE
+/// concept), otherwise the config with its icon path converted to `.ico`
+/// via the existing PNG conversion helper if it wasn't already one.
+async fn resolve_resource_config(
+    target_os: &str,
+    config: Option<ResourceConfig>,
+) -> Result<Option<ResourceConfig>, String> {
+    if target_os != "windows" {
+        return Ok(None);
+    }
+    let Some(mut config) = config else { return Ok(None) };
+
+    if config.icon.extension().and_then(|e| e.to_str()) != Some("ico") {
+        let ico_path = convert_png_to_ico(config.icon.to_string_lossy().to_string()).await?;
+        config.icon = PathBuf::from(ico_path);
+    }
+
+    Ok(Some(config))
 }
 
 /// Run professional installer build using build orchestrator API
@@ -2435,6 +5182,39 @@ pub async fn run_installer_build(
         project_path.join("output").to_string_lossy().to_string()
     };
     
+    let target_os = request.target_os.unwrap_or_else(|| {
+        if cfg!(target_os = "windows") {
+            "windows".to_string()
+        } else if cfg!(target_os = "macos") {
+            "macos".to_string()
+        } else {
+            "linux".to_string()
+        }
+    });
+    let resource_config = resolve_resource_config(&target_os, request.resource_config).await?;
+
+    let protected_payload_path = match &request.payload_protection {
+        Some(config) => Some(seal_installer_payload(
+            std::path::Path::new(&request.project_path),
+            std::path::Path::new(&output_dir),
+            config,
+        )?),
+        None => None,
+    };
+
+    let backend_sidecar_path = match &request.backend_sidecar_path {
+        Some(source) => {
+            window.emit("compilation-progress", CompilationProgress {
+                job_id: job_id.clone(),
+                progress: 8,
+                message: "Bundling backend sidecar...".to_string(),
+                stage: "preparing".to_string(),
+            }).ok();
+            Some(bundle_backend_sidecar(std::path::Path::new(source), std::path::Path::new(&output_dir), &target_os)?)
+        }
+        None => None,
+    };
+
     // Build API request payload
     let api_payload = serde_json::json!({
         "project_name": request.project_name,
@@ -2449,9 +5229,15 @@ pub async fn run_installer_build(
         "distribution_type": request.distribution_type,
         "create_desktop_shortcut": request.create_desktop_shortcut.unwrap_or(true),
         "create_start_menu": request.create_start_menu.unwrap_or(true),
-        "output_dir": output_dir
+        "output_dir": output_dir,
+        "updater_endpoint": request.updater_endpoint,
+        "updater_public_key": request.updater_public_key,
+        "target_os": target_os,
+        "resource_config": resource_config,
+        "protected_payload_path": protected_payload_path,
+        "backend_sidecar_path": backend_sidecar_path,
     });
-    
+
     window.emit("compilation-progress", CompilationProgress {
         job_id: job_id.clone(),
         progress: 10,
@@ -2555,16 +5341,86 @@ pub struct NsisStatus {
     pub path: Option<String>,
 }
 
+/// On-disk record of the last successful `check_nsis_installed` detection,
+/// modeled on rustfmt's toolchain stamp file: remembers what was found so a
+/// repeat call can skip re-scanning the hardcoded install paths and `where`
+/// and just re-verify the single remembered one.
+#[derive(Debug, Serialize, Deserialize)]
+struct NsisStamp {
+    path: String,
+    version: String,
+}
+
+/// Where the NSIS detection stamp lives, next to the other app-managed
+/// cache state.
+fn nsis_stamp_path() -> PathBuf {
+    toolchain::managed_runtime_root().join("nsis.stamp")
+}
+
+/// Read and parse the stamp at `stamp_path`, returning `None` for a
+/// missing or corrupt (unparseable) file - both treated as "out of date"
+/// by the caller, same as a version mismatch.
+fn read_stamp(stamp_path: &std::path::Path) -> Option<NsisStamp> {
+    let contents = std::fs::read_to_string(stamp_path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Whether the stamp at `stamp_path` no longer reflects reality: missing,
+/// corrupt, recorded version doesn't match `current_version`, or the
+/// recorded path no longer exists on disk.
+fn program_out_of_date(stamp_path: &std::path::Path, current_version: &str) -> bool {
+    match read_stamp(stamp_path) {
+        Some(stamp) => stamp.version != current_version || !std::path::Path::new(&stamp.path).exists(),
+        None => true,
+    }
+}
+
+/// Persist a fresh detection result so the next call can skip straight to
+/// re-verifying `path` instead of re-scanning every candidate location.
+fn update_stamp(stamp_path: &std::path::Path, path: &str, version: &str) {
+    let stamp = NsisStamp { path: path.to_string(), version: version.to_string() };
+    if let Ok(json) = serde_json::to_string(&stamp) {
+        if let Some(parent) = stamp_path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+        std::fs::write(stamp_path, json).ok();
+    }
+}
+
+/// Re-run `/VERSION` against a previously stamped path, returning the
+/// `NsisStatus` built from it if it's still there and still reports the
+/// stamped version - the fast path that avoids rescanning every candidate
+/// install location.
+async fn check_stamped_nsis(stamp: &NsisStamp) -> Option<NsisStatus> {
+    let output = Command::new(&stamp.path).args(["/VERSION"]).output().await.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if program_out_of_date(&nsis_stamp_path(), &version) {
+        return None;
+    }
+    Some(NsisStatus { installed: true, version: Some(version), path: Some(stamp.path.clone()) })
+}
+
 /// Check if NSIS (Nullsoft Scriptable Install System) is installed
 /// Used for creating professional Windows installers
 #[tauri::command]
 pub async fn check_nsis_installed() -> Result<NsisStatus, String> {
+    let stamp_path = nsis_stamp_path();
+
+    if let Some(stamp) = read_stamp(&stamp_path) {
+        if let Some(status) = check_stamped_nsis(&stamp).await {
+            return Ok(status);
+        }
+    }
+
     // Common NSIS installation paths on Windows
     let nsis_paths = [
         r"C:\Program Files (x86)\NSIS\makensis.exe",
         r"C:\Program Files\NSIS\makensis.exe",
     ];
-    
+
     // First check common paths
     for path in &nsis_paths {
         let path_buf = std::path::PathBuf::from(path);
@@ -2577,7 +5433,11 @@ pub async fn check_nsis_installed() -> Result<NsisStatus, String> {
                 .ok()
                 .filter(|r| r.status.success())
                 .map(|r| String::from_utf8_lossy(&r.stdout).trim().to_string());
-            
+
+            if let Some(ref v) = version {
+                update_stamp(&stamp_path, path, v);
+            }
+
             return Ok(NsisStatus {
                 installed: true,
                 version,
@@ -2585,20 +5445,20 @@ pub async fn check_nsis_installed() -> Result<NsisStatus, String> {
             });
         }
     }
-    
+
     // Check if makensis is in PATH
     let which_result = Command::new("where")
         .args(["makensis"])
         .output()
         .await;
-    
+
     match which_result {
         Ok(result) if result.status.success() => {
             let path = String::from_utf8_lossy(&result.stdout)
                 .lines()
                 .next()
                 .map(|s| s.trim().to_string());
-            
+
             // Try to get version
             let version = if let Some(ref p) = path {
                 Command::new(p)
@@ -2611,7 +5471,11 @@ pub async fn check_nsis_installed() -> Result<NsisStatus, String> {
             } else {
                 None
             };
-            
+
+            if let (Some(ref p), Some(ref v)) = (&path, &version) {
+                update_stamp(&stamp_path, p, v);
+            }
+
             Ok(NsisStatus {
                 installed: true,
                 version,
@@ -2625,3 +5489,245 @@ pub async fn check_nsis_installed() -> Result<NsisStatus, String> {
         }),
     }
 }
+
+/// Which desktop OS an [`InstallerToolStatus`] check targets.
+#[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallerToolOs {
+    MacOs,
+    Linux,
+}
+
+/// Which native packaging tool an [`InstallerToolStatus`] check targets.
+#[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallerToolKind {
+    MacPkgbuild,
+    LinuxAppImage,
+    LinuxDpkgDeb,
+}
+
+/// Generalized installer-tool status, parallel to [`NsisStatus`] but
+/// covering the macOS/Linux packaging tools too.
+#[derive(Clone, Serialize, Debug)]
+pub struct InstallerToolStatus {
+    pub os: InstallerToolOs,
+    pub kind: InstallerToolKind,
+    pub installed: bool,
+    pub version: Option<String>,
+    pub path: Option<String>,
+}
+
+/// Resolve `tool_name`'s location via `which` and attempt `tool_name
+/// version_args` to read a version string. Shared by the per-tool
+/// installer status commands below.
+async fn locate_unix_tool(tool_name: &str, version_args: &[&str]) -> (bool, Option<String>, Option<String>) {
+    let which_result = Command::new("which").arg(tool_name).output().await;
+    let path = match which_result {
+        Ok(result) if result.status.success() => {
+            String::from_utf8_lossy(&result.stdout).lines().next().map(|s| s.trim().to_string())
+        }
+        _ => None,
+    };
+    let Some(path) = path else {
+        return (false, None, None);
+    };
+    let version = Command::new(tool_name)
+        .args(version_args)
+        .output()
+        .await
+        .ok()
+        .filter(|r| r.status.success())
+        .map(|r| String::from_utf8_lossy(&r.stdout).trim().to_string());
+    (true, version, Some(path))
+}
+
+/// Check if macOS's `pkgbuild`/`productbuild` (bundled with the Xcode
+/// Command Line Tools) are available, for building `.pkg` installers.
+#[tauri::command]
+pub async fn check_pkgbuild_installed() -> Result<InstallerToolStatus, String> {
+    let (installed, version, path) = locate_unix_tool("pkgbuild", &["--version"]).await;
+    Ok(InstallerToolStatus {
+        os: InstallerToolOs::MacOs,
+        kind: InstallerToolKind::MacPkgbuild,
+        installed,
+        version,
+        path,
+    })
+}
+
+/// Check if `appimagetool` is available, for building Linux `.AppImage`s.
+#[tauri::command]
+pub async fn check_appimagetool_installed() -> Result<InstallerToolStatus, String> {
+    let (installed, version, path) = locate_unix_tool("appimagetool", &["--version"]).await;
+    Ok(InstallerToolStatus {
+        os: InstallerToolOs::Linux,
+        kind: InstallerToolKind::LinuxAppImage,
+        installed,
+        version,
+        path,
+    })
+}
+
+/// Check if `dpkg-deb` is available, for building Linux `.deb` installers.
+#[tauri::command]
+pub async fn check_dpkg_deb_installed() -> Result<InstallerToolStatus, String> {
+    let (installed, version, path) = locate_unix_tool("dpkg-deb", &["--version"]).await;
+    Ok(InstallerToolStatus {
+        os: InstallerToolOs::Linux,
+        kind: InstallerToolKind::LinuxDpkgDeb,
+        installed,
+        version,
+        path,
+    })
+}
+
+// ============ Multi-target installer manifest ============
+
+/// Every target triple this crate knows how to produce a native installer
+/// for. The single source of truth a CI run (or `generate_installer_manifest`
+/// itself) walks instead of calling `check_nsis_installed`-style functions
+/// ad hoc for whichever platform happens to be asked about.
+const INSTALLER_TARGETS: &[&str] = &[
+    "x86_64-pc-windows-msvc",
+    "i686-pc-windows-gnu",
+    "aarch64-pc-windows-msvc",
+    "x86_64-apple-darwin",
+    "aarch64-apple-darwin",
+    "x86_64-unknown-linux-gnu",
+];
+
+/// Which native packaging tool produces the installer for a given target
+/// triple, parallel to [`NsisStatus`]/[`InstallerToolStatus`] but unified
+/// across all three desktop OSes instead of split by detection function.
+#[derive(Clone, Copy, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PackagingBackend {
+    Nsis,
+    MacPkgbuild,
+    LinuxAppImage,
+    LinuxDpkgDeb,
+}
+
+impl PackagingBackend {
+    /// File extension the backend's output installer uses.
+    fn artifact_extension(self) -> &'static str {
+        match self {
+            PackagingBackend::Nsis => "exe",
+            PackagingBackend::MacPkgbuild => "pkg",
+            PackagingBackend::LinuxAppImage => "AppImage",
+            PackagingBackend::LinuxDpkgDeb => "deb",
+        }
+    }
+}
+
+/// Every backend capable of targeting `triple`, in the order they're
+/// preferred. Linux has two competing packaging formats, so both are
+/// listed; the manifest uses whichever is actually installed.
+fn backends_for_target(triple: &str) -> Vec<PackagingBackend> {
+    if triple.contains("windows") {
+        vec![PackagingBackend::Nsis]
+    } else if triple.contains("apple-darwin") {
+        vec![PackagingBackend::MacPkgbuild]
+    } else if triple.contains("linux") {
+        vec![PackagingBackend::LinuxAppImage, PackagingBackend::LinuxDpkgDeb]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Resolved (or unresolved) status of one backend, reused for whichever
+/// candidate `backends_for_target` proposes.
+async fn probe_backend(backend: PackagingBackend) -> (bool, Option<String>, Option<String>) {
+    match backend {
+        PackagingBackend::Nsis => {
+            let status = check_nsis_installed().await.unwrap_or(NsisStatus { installed: false, version: None, path: None });
+            (status.installed, status.version, status.path)
+        }
+        PackagingBackend::MacPkgbuild => locate_unix_tool("pkgbuild", &["--version"]).await,
+        PackagingBackend::LinuxAppImage => locate_unix_tool("appimagetool", &["--version"]).await,
+        PackagingBackend::LinuxDpkgDeb => locate_unix_tool("dpkg-deb", &["--version"]).await,
+    }
+}
+
+/// One target triple's entry in an [`InstallerManifest`]: whether this host
+/// can currently produce an artifact for it, and with which tool.
+#[derive(Clone, Serialize, Debug)]
+pub struct ManifestEntry {
+    pub target: String,
+    pub backend: Option<PackagingBackend>,
+    pub available: bool,
+    pub version: Option<String>,
+    pub tool_path: Option<String>,
+    pub output_artifact: String,
+}
+
+/// Per-target-triple packaging availability for the current host, so a CI
+/// run can ask "can I build an NSIS installer for `x86_64-pc-windows-msvc`
+/// on this host?" or enumerate every producible artifact in one pass.
+#[derive(Clone, Serialize, Debug)]
+pub struct InstallerManifest {
+    pub host: String,
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl InstallerManifest {
+    /// Whether any entry can currently produce an installer for `target`.
+    pub fn can_build(&self, target: &str) -> bool {
+        self.entries.iter().any(|e| e.target == target && e.available)
+    }
+}
+
+/// Probe every known target triple's packaging backend(s) on this host and
+/// assemble the results into an [`InstallerManifest`], naming each
+/// producible artifact `{project_name}-{target}.{ext}`.
+pub async fn build_installer_manifest(project_name: &str) -> InstallerManifest {
+    let host = std::env::consts::ARCH.to_string() + "-" + std::env::consts::OS;
+    let mut entries = Vec::new();
+
+    for &target in INSTALLER_TARGETS {
+        let mut resolved: Option<(PackagingBackend, String, Option<String>)> = None;
+        for backend in backends_for_target(target) {
+            let (installed, version, path) = probe_backend(backend).await;
+            if installed {
+                resolved = Some((backend, version.unwrap_or_default(), path));
+                break;
+            }
+        }
+
+        let (backend, available, version, tool_path) = match &resolved {
+            Some((backend, version, path)) => (Some(*backend), true, Some(version.clone()), path.clone()),
+            None => (backends_for_target(target).into_iter().next(), false, None, None),
+        };
+
+        let extension = backend.map(PackagingBackend::artifact_extension).unwrap_or("bin");
+        entries.push(ManifestEntry {
+            target: target.to_string(),
+            backend,
+            available,
+            version,
+            tool_path,
+            output_artifact: format!("{project_name}-{target}.{extension}"),
+        });
+    }
+
+    InstallerManifest { host, entries }
+}
+
+/// Build an [`InstallerManifest`] for `project_name` and, if `output_path`
+/// is given, also write it to disk as JSON so a CI pipeline can consume it
+/// without re-running the probes itself.
+#[tauri::command]
+pub async fn generate_installer_manifest(
+    project_name: String,
+    output_path: Option<String>,
+) -> Result<InstallerManifest, String> {
+    let manifest = build_installer_manifest(&project_name).await;
+
+    if let Some(path) = output_path {
+        let json = serde_json::to_string_pretty(&manifest).map_err(|e| format!("Failed to serialize manifest: {e}"))?;
+        std::fs::write(&path, json).map_err(|e| format!("Failed to write manifest to {path}: {e}"))?;
+    }
+
+    Ok(manifest)
+}
@@ -2,10 +2,47 @@
 // Downloads project files from the CodeVault server for local compilation
 
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 use tauri::Emitter;
 use tokio::io::AsyncWriteExt;
 use reqwest::Client;
+use futures_util::StreamExt;
+
+/// HTTP response header the server may set with the archive's SHA-256,
+/// checked when `expected_sha256` isn't passed explicitly.
+const SHA256_HEADER: &str = "X-Content-SHA256";
+
+/// Fetch the expected digest for a download: the caller-supplied value if
+/// given, else the `X-Content-SHA256` response header, else a sidecar
+/// `<download_url>.sha256` file if the server publishes one. Returns
+/// `None` (not an error) if nothing is available - integrity checking is
+/// best-effort against whatever the server actually offers.
+async fn resolve_expected_sha256(
+    client: &Client,
+    download_url: &str,
+    response_headers: &reqwest::header::HeaderMap,
+    expected_sha256: Option<String>,
+) -> Option<String> {
+    if let Some(expected) = expected_sha256 {
+        return Some(expected.trim().to_lowercase());
+    }
+
+    if let Some(header) = response_headers.get(SHA256_HEADER) {
+        if let Ok(value) = header.to_str() {
+            return Some(value.trim().to_lowercase());
+        }
+    }
+
+    let sidecar_url = format!("{download_url}.sha256");
+    let response = client.get(&sidecar_url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let body = response.text().await.ok()?;
+    // Sidecar files are typically "<hash>  <filename>"; take the first token.
+    body.split_whitespace().next().map(|s| s.trim().to_lowercase())
+}
 
 /// Download progress event
 #[derive(Clone, Serialize)]
@@ -33,6 +70,7 @@ pub async fn download_project_for_compilation(
     server_url: String,
     auth_token: String,
     target_dir: Option<String>,
+    expected_sha256: Option<String>,
 ) -> Result<String, String> {
     // Emit start event
     window.emit("download-progress", DownloadProgress {
@@ -66,54 +104,110 @@ pub async fn download_project_for_compilation(
     
     // Build download URL
     let download_url = format!("{}/api/v1/projects/{}/download-source", server_url, project_id);
-    
+
     // Create HTTP client
     let client = Client::new();
-    
-    // Make request
-    let response = client
-        .get(&download_url)
-        .header("Authorization", format!("Bearer {}", auth_token))
-        .send()
-        .await
-        .map_err(|e| format!("Failed to connect to server: {}", e))?;
-    
-    if !response.status().is_success() {
+
+    // Resume a previous partial download, if one is sitting on disk, by
+    // asking the server for everything after what we already have instead
+    // of restarting from zero.
+    let zip_path = target_path.join("project_source.zip");
+    let existing_len = tokio::fs::metadata(&zip_path).await.map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(&download_url).header("Authorization", format!("Bearer {}", auth_token));
+    if existing_len > 0 {
+        request = request.header("Range", format!("bytes={}-", existing_len));
+    }
+    let response = request.send().await.map_err(|e| format!("Failed to connect to server: {}", e))?;
+
+    let resumed = existing_len > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    if !response.status().is_success() && response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
         return Err(format!("Server returned error: {}", response.status()));
     }
-    
+
+    let expected_digest = resolve_expected_sha256(&client, &download_url, response.headers(), expected_sha256).await;
+
     window.emit("download-progress", DownloadProgress {
         project_id: project_id.clone(),
         progress: 20,
-        message: "Downloading project files...".to_string(),
+        message: if resumed { format!("Resuming download from byte {existing_len}...") } else { "Downloading project files...".to_string() },
         stage: "downloading".to_string(),
     }).ok();
-    
-    // Get content length for progress tracking
-    let _content_length = response.content_length().unwrap_or(0);
-    
-    // Download to temporary zip file
-    let zip_path = target_path.join("project_source.zip");
-    let mut file = tokio::fs::File::create(&zip_path)
-        .await
-        .map_err(|e| format!("Failed to create temp file: {}", e))?;
-    
-    // Stream the download
-    let bytes = response.bytes()
-        .await
-        .map_err(|e| format!("Failed to download file: {}", e))?;
-    
-    file.write_all(&bytes)
-        .await
-        .map_err(|e| format!("Failed to write file: {}", e))?;
-    
+
+    let remaining_len = response.content_length().unwrap_or(0);
+    let total_len = if resumed { existing_len + remaining_len } else { remaining_len };
+
+    // Re-hash whatever's already on disk before appending to it, so the
+    // final digest still covers the whole file without having buffered
+    // the whole archive in memory for this download.
+    let mut hasher = Sha256::new();
+    if resumed {
+        if let Ok(existing_bytes) = tokio::fs::read(&zip_path).await {
+            hasher.update(&existing_bytes);
+        }
+    }
+
+    let mut file = if resumed {
+        tokio::fs::OpenOptions::new().append(true).open(&zip_path).await.map_err(|e| format!("Failed to reopen partial download: {}", e))?
+    } else {
+        tokio::fs::File::create(&zip_path).await.map_err(|e| format!("Failed to create temp file: {}", e))?
+    };
+
+    // Stream chunks straight to disk instead of buffering the whole
+    // archive in memory, so a large project doesn't spike RAM and a
+    // dropped connection only loses the unwritten tail.
+    let mut bytes_written = if resumed { existing_len } else { 0 };
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Failed to download file: {}", e))?;
+        file.write_all(&chunk).await.map_err(|e| format!("Failed to write file: {}", e))?;
+        hasher.update(&chunk);
+        bytes_written += chunk.len() as u64;
+
+        if total_len > 0 {
+            let fraction = bytes_written as f64 / total_len as f64;
+            let progress = 20 + ((fraction * 40.0) as u32).min(40);
+            window.emit("download-progress", DownloadProgress {
+                project_id: project_id.clone(),
+                progress,
+                message: format!("Downloaded {} / {} bytes", bytes_written, total_len),
+                stage: "downloading".to_string(),
+            }).ok();
+        }
+    }
+    file.flush().await.map_err(|e| format!("Failed to flush file: {}", e))?;
+
     window.emit("download-progress", DownloadProgress {
         project_id: project_id.clone(),
         progress: 60,
-        message: format!("Downloaded {} bytes", bytes.len()),
+        message: format!("Downloaded {} bytes", bytes_written),
         stage: "downloaded".to_string(),
     }).ok();
-    
+
+    if let Some(expected) = &expected_digest {
+        window.emit("download-progress", DownloadProgress {
+            project_id: project_id.clone(),
+            progress: 65,
+            message: "Verifying archive integrity...".to_string(),
+            stage: "verifying".to_string(),
+        }).ok();
+
+        let actual = format!("{:x}", hasher.finalize());
+        if &actual != expected {
+            let error_msg = format!(
+                "Downloaded archive failed integrity check: expected sha256 {expected}, got {actual}"
+            );
+            window.emit("download-result", DownloadResult {
+                project_id: project_id.clone(),
+                success: false,
+                extracted_path: None,
+                error_message: Some(error_msg.clone()),
+            }).ok();
+            tokio::fs::remove_file(&zip_path).await.ok();
+            return Err(error_msg);
+        }
+    }
+
     // Extract zip file
     window.emit("download-progress", DownloadProgress {
         project_id: project_id.clone(),
@@ -121,27 +215,41 @@ pub async fn download_project_for_compilation(
         message: "Extracting files...".to_string(),
         stage: "extracting".to_string(),
     }).ok();
-    
+
     // Use std::fs for zip extraction (blocking, but wrapped in spawn_blocking)
     let zip_path_clone = zip_path.clone();
     let target_path_clone = target_path.clone();
-    
+
     tokio::task::spawn_blocking(move || {
         let file = std::fs::File::open(&zip_path_clone)
             .map_err(|e| format!("Failed to open zip file: {}", e))?;
-        
+
         let mut archive = zip::ZipArchive::new(file)
             .map_err(|e| format!("Failed to read zip archive: {}", e))?;
-        
+
+        let target_root = target_path_clone.canonicalize().unwrap_or_else(|_| target_path_clone.clone());
+
         for i in 0..archive.len() {
             let mut file = archive.by_index(i)
                 .map_err(|e| format!("Failed to read file from archive: {}", e))?;
-            
+
             let outpath = match file.enclosed_name() {
                 Some(path) => target_path_clone.join(path),
                 None => continue,
             };
-            
+
+            // Zip-slip hardening: `enclosed_name()` already rejects `..`
+            // components, but a symlinked parent directory or an absolute
+            // path slipping past that check could still resolve outside
+            // `target_path` once joined - refuse to write anywhere that
+            // doesn't stay under it.
+            let resolved_parent = outpath.parent()
+                .map(|p| if p.exists() { p.canonicalize().unwrap_or_else(|_| p.to_path_buf()) } else { p.to_path_buf() })
+                .unwrap_or_else(|| target_path_clone.clone());
+            if !resolved_parent.starts_with(&target_root) && resolved_parent != target_root {
+                return Err(format!("Archive entry escapes target directory: {}", file.name()));
+            }
+
             if file.name().ends_with('/') {
                 std::fs::create_dir_all(&outpath).ok();
             } else {
@@ -156,13 +264,13 @@ pub async fn download_project_for_compilation(
                     .map_err(|e| format!("Failed to extract file: {}", e))?;
             }
         }
-        
+
         Ok::<(), String>(())
     })
     .await
     .map_err(|e| format!("Extraction task failed: {}", e))?
     .map_err(|e| e)?;
-    
+
     // Clean up zip file
     tokio::fs::remove_file(&zip_path).await.ok();
     
@@ -226,6 +334,7 @@ pub async fn download_and_prepare_for_compile(
     server_url: String,
     auth_token: String,
     target_dir: Option<String>,
+    expected_sha256: Option<String>,
 ) -> Result<String, String> {
     // First download the project
     let extracted_path = download_project_for_compilation(
@@ -234,6 +343,7 @@ pub async fn download_and_prepare_for_compile(
         server_url,
         auth_token,
         target_dir,
+        expected_sha256,
     ).await?;
     
     // Emit ready event
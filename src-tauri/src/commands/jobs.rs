@@ -0,0 +1,95 @@
+// Registry of in-flight compile jobs so the UI can cancel one mid-build.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A running job's cancellation flag and the OS process id(s) spawned for
+/// it. Stage loops poll `cancelled` between commands and after each parsed
+/// output line; `cancel_compile` flips it and kills every tracked pid.
+#[derive(Default)]
+struct JobHandle {
+    cancelled: Arc<AtomicBool>,
+    pids: Vec<u32>,
+}
+
+/// Shared `tauri::State` tracking every Nuitka compile job currently
+/// running, keyed by the `job_id` returned from `run_nuitka_compilation`
+/// (or the `watch_id` of a watch session). `run_nodejs_compilation` jobs
+/// are not registered here.
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: Mutex<HashMap<String, JobHandle>>,
+}
+
+impl JobRegistry {
+    /// Start tracking `job_id`, returning the cancellation flag the caller
+    /// should check at each stage boundary.
+    pub fn register(&self, job_id: &str) -> Arc<AtomicBool> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.jobs.lock().unwrap().insert(
+            job_id.to_string(),
+            JobHandle { cancelled: Arc::clone(&cancelled), pids: Vec::new() },
+        );
+        cancelled
+    }
+
+    /// Record a child process id spawned on behalf of `job_id` so it can be
+    /// torn down on cancellation.
+    pub fn track_pid(&self, job_id: &str, pid: u32) {
+        if let Some(handle) = self.jobs.lock().unwrap().get_mut(job_id) {
+            handle.pids.push(pid);
+        }
+    }
+
+    /// Stop tracking `job_id`. Safe to call whether or not it was
+    /// registered, and whether or not it was cancelled.
+    pub fn unregister(&self, job_id: &str) {
+        self.jobs.lock().unwrap().remove(job_id);
+    }
+
+    /// Signal cancellation and kill every tracked process tree for
+    /// `job_id`. Returns `false` if no job with that id is registered.
+    pub fn cancel(&self, job_id: &str) -> bool {
+        let pids = {
+            let jobs = self.jobs.lock().unwrap();
+            match jobs.get(job_id) {
+                Some(handle) => {
+                    handle.cancelled.store(true, Ordering::SeqCst);
+                    handle.pids.clone()
+                }
+                None => return false,
+            }
+        };
+
+        for pid in pids {
+            kill_process_tree(pid);
+        }
+        true
+    }
+}
+
+/// Kill `pid` and every process it spawned. On Windows, `taskkill /T`
+/// walks the process tree for us; on Unix, the Nuitka child is spawned in
+/// its own process group (group id == pid), so signalling the negative pid
+/// reaches the whole tree instead of just the leader. npm and pip children
+/// are neither tracked nor given their own process group.
+#[cfg(target_os = "windows")]
+fn kill_process_tree(pid: u32) {
+    let _ = std::process::Command::new("taskkill")
+        .args(["/T", "/F", "/PID", &pid.to_string()])
+        .output();
+}
+
+#[cfg(not(target_os = "windows"))]
+fn kill_process_tree(pid: u32) {
+    let _ = std::process::Command::new("kill")
+        .args(["-TERM", &format!("-{pid}")])
+        .output();
+}
+
+/// Cancel a running compile job by id, killing its process tree. Returns
+/// `false` if the job already finished or never existed.
+#[tauri::command]
+pub fn cancel_compile(job_id: String, registry: tauri::State<'_, JobRegistry>) -> bool {
+    registry.cancel(&job_id)
+}
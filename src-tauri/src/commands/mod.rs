@@ -0,0 +1,12 @@
+pub mod analytics;
+pub mod benchmark;
+pub mod build_log;
+pub mod compiler;
+pub mod downloader;
+pub mod jobs;
+pub mod payload_protection;
+pub mod projects;
+pub mod runtime_bootstrap;
+pub mod settings;
+pub mod size_report;
+pub mod toolchain;
@@ -0,0 +1,162 @@
+// Optional integrity/recoverability layer for bundled installer payloads,
+// adjacent to the NSIS packaging step in `compiler.rs`. Files destined for
+// an installer are split into shards, Reed-Solomon parity shards are
+// computed over them so the payload survives a configurable number of
+// corrupted/missing shards, and every shard (data and parity alike) is
+// sealed with ChaCha20-Poly1305 so tampering is caught before
+// reconstruction even starts. The default (no `PayloadProtection` supplied
+// to `run_installer_build`) keeps today's plain, unprotected behavior.
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::{rand_core::RngCore, Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use reed_solomon_erasure::galois_8::ReedSolomon;
+use serde::{Deserialize, Serialize};
+
+/// Shard/parity/encryption configuration for one payload. `data_shards`
+/// and `parity_shards` follow Reed-Solomon's usual meaning: the payload
+/// survives losing up to `parity_shards` of the `data_shards +
+/// parity_shards` total shards. `key` is a base64-encoded 32-byte
+/// ChaCha20-Poly1305 key.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PayloadProtection {
+    pub data_shards: usize,
+    pub parity_shards: usize,
+    pub key: String,
+}
+
+/// One shard after Reed-Solomon encoding and ChaCha20-Poly1305 sealing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedShard {
+    pub index: usize,
+    pub is_parity: bool,
+    pub nonce: String,
+    pub ciphertext: Vec<u8>,
+}
+
+/// A payload after [`protect_payload`], ready to be written alongside the
+/// installer and later fed to [`verify_and_repair`] at extraction time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectedPayload {
+    pub data_shards: usize,
+    pub parity_shards: usize,
+    pub shard_len: usize,
+    pub original_len: usize,
+    pub shards: Vec<SealedShard>,
+}
+
+/// Decode `config.key` into the 32-byte ChaCha20-Poly1305 key it must be.
+fn parse_key(encoded: &str) -> Result<Key, String> {
+    let bytes = BASE64.decode(encoded).map_err(|e| format!("Invalid payload protection key: {e}"))?;
+    if bytes.len() != 32 {
+        return Err(format!("Payload protection key must be 32 bytes, got {}", bytes.len()));
+    }
+    Ok(*Key::from_slice(&bytes))
+}
+
+/// A fresh random 12-byte ChaCha20-Poly1305 nonce.
+fn random_nonce() -> [u8; 12] {
+    let mut bytes = [0u8; 12];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Split `data` into `config.data_shards` equal-length (zero-padded)
+/// chunks, compute `config.parity_shards` Reed-Solomon parity shards over
+/// them, then seal every shard under its own random nonce.
+pub fn protect_payload(data: &[u8], config: &PayloadProtection) -> Result<ProtectedPayload, String> {
+    if config.data_shards == 0 {
+        return Err("data_shards must be at least 1".to_string());
+    }
+    let key = parse_key(&config.key)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+
+    let shard_len = data.len().div_ceil(config.data_shards).max(1);
+    let mut shards: Vec<Vec<u8>> = (0..config.data_shards)
+        .map(|i| {
+            let start = i * shard_len;
+            let end = (start + shard_len).min(data.len());
+            let mut shard = vec![0u8; shard_len];
+            if start < data.len() {
+                shard[..end - start].copy_from_slice(&data[start..end]);
+            }
+            shard
+        })
+        .collect();
+    shards.extend((0..config.parity_shards).map(|_| vec![0u8; shard_len]));
+
+    let rs = ReedSolomon::new(config.data_shards, config.parity_shards)
+        .map_err(|e| format!("Failed to build Reed-Solomon encoder: {e}"))?;
+    rs.encode(&mut shards).map_err(|e| format!("Reed-Solomon encoding failed: {e}"))?;
+
+    let shards = shards
+        .into_iter()
+        .enumerate()
+        .map(|(index, shard)| {
+            let nonce_bytes = random_nonce();
+            let nonce = Nonce::from_slice(&nonce_bytes);
+            let ciphertext = cipher
+                .encrypt(nonce, shard.as_ref())
+                .map_err(|e| format!("Failed to seal shard {index}: {e}"))?;
+            Ok(SealedShard {
+                index,
+                is_parity: index >= config.data_shards,
+                nonce: BASE64.encode(nonce_bytes),
+                ciphertext,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(ProtectedPayload {
+        data_shards: config.data_shards,
+        parity_shards: config.parity_shards,
+        shard_len,
+        original_len: data.len(),
+        shards,
+    })
+}
+
+/// Verify every sealed shard's Poly1305 tag, reconstruct any shard that
+/// fails authentication (treated as missing/corrupt) via Reed-Solomon
+/// parity, and reassemble the original bytes. Fails loudly - rather than
+/// returning truncated or tampered data - if more shards are unreadable
+/// than `parity_shards` can recover.
+pub fn verify_and_repair(payload: &ProtectedPayload, config: &PayloadProtection) -> Result<Vec<u8>, String> {
+    let key = parse_key(&config.key)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+
+    let total = payload.data_shards + payload.parity_shards;
+    let mut shards: Vec<Option<Vec<u8>>> = vec![None; total];
+    let mut missing = 0usize;
+
+    for sealed in &payload.shards {
+        let nonce_bytes = BASE64
+            .decode(&sealed.nonce)
+            .map_err(|e| format!("Corrupt shard {}: bad nonce: {e}", sealed.index))?;
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        match cipher.decrypt(nonce, sealed.ciphertext.as_ref()) {
+            Ok(plain) => shards[sealed.index] = Some(plain),
+            Err(_) => missing += 1,
+        }
+    }
+
+    if missing > payload.parity_shards {
+        return Err(format!(
+            "Cannot reconstruct payload: {missing} shard(s) failed authentication, only {} parity shard(s) available",
+            payload.parity_shards
+        ));
+    }
+
+    if missing > 0 {
+        let rs = ReedSolomon::new(payload.data_shards, payload.parity_shards)
+            .map_err(|e| format!("Failed to build Reed-Solomon decoder: {e}"))?;
+        rs.reconstruct(&mut shards).map_err(|e| format!("Reed-Solomon reconstruction failed: {e}"))?;
+    }
+
+    let mut original = Vec::with_capacity(payload.original_len);
+    for shard in shards.into_iter().take(payload.data_shards) {
+        let shard = shard.ok_or_else(|| "Reconstruction left a data shard empty".to_string())?;
+        original.extend_from_slice(&shard);
+    }
+    original.truncate(payload.original_len);
+    Ok(original)
+}
@@ -1,6 +1,9 @@
 // Project management commands
+use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
+use crate::db::DbCtx;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Project {
     pub id: String,
@@ -13,6 +16,21 @@ pub struct Project {
     pub updated_at: String,
 }
 
+impl Project {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Project {
+            id: row.get("id")?,
+            name: row.get("name")?,
+            description: row.get("description")?,
+            local_path: row.get("local_path")?,
+            entry_file: row.get("entry_file")?,
+            cloud_synced: row.get::<_, i64>("cloud_synced")? != 0,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateProjectRequest {
     pub name: String,
@@ -22,12 +40,15 @@ pub struct CreateProjectRequest {
 
 /// Create a new local project
 #[tauri::command]
-pub async fn create_project(request: CreateProjectRequest) -> Result<Project, String> {
+pub async fn create_project(
+    db: tauri::State<'_, DbCtx>,
+    request: CreateProjectRequest,
+) -> Result<Project, String> {
     let id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now().to_rfc3339();
-    
+
     let project = Project {
-        id: id.clone(),
+        id,
         name: request.name,
         description: request.description,
         local_path: request.local_path,
@@ -36,40 +57,78 @@ pub async fn create_project(request: CreateProjectRequest) -> Result<Project, St
         created_at: now.clone(),
         updated_at: now,
     };
-    
-    // TODO: Save to SQLite database
-    // For now, just return the project
-    
+
+    let conn = db.get()?;
+    conn.execute(
+        "INSERT INTO projects (id, name, description, local_path, entry_file, cloud_synced, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            project.id,
+            project.name,
+            project.description,
+            project.local_path,
+            project.entry_file,
+            project.cloud_synced as i64,
+            project.created_at,
+            project.updated_at,
+        ],
+    )
+    .map_err(|e| format!("Failed to save project: {e}"))?;
+
     Ok(project)
 }
 
 /// List all local projects
 #[tauri::command]
-pub async fn list_projects() -> Result<Vec<Project>, String> {
-    // TODO: Read from SQLite database
-    Ok(vec![])
+pub async fn list_projects(db: tauri::State<'_, DbCtx>) -> Result<Vec<Project>, String> {
+    let conn = db.get()?;
+    let mut stmt = conn
+        .prepare("SELECT id, name, description, local_path, entry_file, cloud_synced, created_at, updated_at FROM projects ORDER BY updated_at DESC")
+        .map_err(|e| format!("Failed to query projects: {e}"))?;
+
+    let rows = stmt
+        .query_map([], Project::from_row)
+        .map_err(|e| format!("Failed to query projects: {e}"))?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(|e| format!("Failed to read projects: {e}"))
 }
 
 /// Get a single project by ID
 #[tauri::command]
-pub async fn get_project(id: String) -> Result<Option<Project>, String> {
-    // TODO: Read from SQLite database
-    let _ = id;
-    Ok(None)
+pub async fn get_project(db: tauri::State<'_, DbCtx>, id: String) -> Result<Option<Project>, String> {
+    let conn = db.get()?;
+    conn.query_row(
+        "SELECT id, name, description, local_path, entry_file, cloud_synced, created_at, updated_at FROM projects WHERE id = ?1",
+        params![id],
+        Project::from_row,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to read project: {e}"))
 }
 
 /// Delete a project
 #[tauri::command]
-pub async fn delete_project(id: String) -> Result<bool, String> {
-    // TODO: Delete from SQLite database
-    let _ = id;
-    Ok(true)
+pub async fn delete_project(db: tauri::State<'_, DbCtx>, id: String) -> Result<bool, String> {
+    let conn = db.get()?;
+    let affected = conn
+        .execute("DELETE FROM projects WHERE id = ?1", params![id])
+        .map_err(|e| format!("Failed to delete project: {e}"))?;
+    Ok(affected > 0)
 }
 
 /// Set the entry file for a project
 #[tauri::command]
-pub async fn set_project_entry(project_id: String, entry_file: String) -> Result<bool, String> {
-    // TODO: Update in SQLite database
-    let _ = (project_id, entry_file);
-    Ok(true)
+pub async fn set_project_entry(
+    db: tauri::State<'_, DbCtx>,
+    project_id: String,
+    entry_file: String,
+) -> Result<bool, String> {
+    let conn = db.get()?;
+    let affected = conn
+        .execute(
+            "UPDATE projects SET entry_file = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+            params![entry_file, project_id],
+        )
+        .map_err(|e| format!("Failed to update project: {e}"))?;
+    Ok(affected > 0)
 }
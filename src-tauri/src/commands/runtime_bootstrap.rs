@@ -0,0 +1,364 @@
+// Download a self-contained Python/Node runtime when the host has neither
+// installed, so a user can go from a clean machine to a working build
+// without leaving the app. Parallel to `install_nuitka`/`install_pillow`,
+// but those assume a Python already exists to run pip with - this is the
+// step before that.
+use tauri::Emitter;
+
+use super::compiler::{CompilationProgress, CompilationResult};
+use super::toolchain::{self, ToolKind};
+
+/// Host OS/arch triple python-build-standalone publishes releases under.
+fn python_build_standalone_triple() -> Result<&'static str, String> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("windows", "x86_64") => Ok("x86_64-pc-windows-msvc"),
+        ("macos", "aarch64") => Ok("aarch64-apple-darwin"),
+        ("macos", "x86_64") => Ok("x86_64-apple-darwin"),
+        ("linux", "x86_64") => Ok("x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") => Ok("aarch64-unknown-linux-gnu"),
+        (os, arch) => Err(format!("No standalone Python build available for {}/{}", os, arch)),
+    }
+}
+
+/// Resolve the date tag (e.g. `"20240726"`) of the most recent
+/// python-build-standalone release via GitHub's releases API, since the
+/// project never publishes a `latest` tag or alias asset - every URL and
+/// filename must embed the real tag.
+async fn latest_python_build_standalone_tag() -> Result<String, String> {
+    let url = "https://api.github.com/repos/indygreg/python-build-standalone/releases/latest";
+    let response = reqwest::Client::new()
+        .get(url)
+        .header("User-Agent", "CodeVault")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to query GitHub releases for python-build-standalone: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("GitHub releases lookup failed for python-build-standalone ({})", response.status()));
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub releases response: {}", e))?;
+    body.get("tag_name")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| "GitHub releases response missing tag_name".to_string())
+}
+
+/// The `(os, arch)` components Node.js names its official tarballs with,
+/// e.g. `node-v20.11.0-darwin-arm64.tar.gz`.
+fn node_dist_components() -> Result<(&'static str, &'static str), String> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("windows", "x86_64") => Ok(("win", "x64")),
+        ("macos", "aarch64") => Ok(("darwin", "arm64")),
+        ("macos", "x86_64") => Ok(("darwin", "x64")),
+        ("linux", "x86_64") => Ok(("linux", "x64")),
+        ("linux", "aarch64") => Ok(("linux", "arm64")),
+        (os, arch) => Err(format!("No Node.js build available for {}/{}", os, arch)),
+    }
+}
+
+fn archive_extension() -> &'static str {
+    if cfg!(windows) { "zip" } else { "tar.gz" }
+}
+
+/// SHA-256 of a file on disk, reusing the same hashing approach the
+/// license wrapper's trailer code uses.
+fn sha256_of_file(path: &std::path::Path) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Fail unless `path`'s SHA-256 matches one of the hex digests found on a
+/// line of `checksums_text` alongside `expected_filename`.
+fn verify_against_checksum_list(path: &std::path::Path, checksums_text: &str, expected_filename: &str) -> Result<(), String> {
+    let expected_hash = checksums_text
+        .lines()
+        .find(|line| line.contains(expected_filename))
+        .and_then(|line| line.split_whitespace().next())
+        .ok_or_else(|| format!("No checksum entry found for {}", expected_filename))?;
+
+    let actual_hash = sha256_of_file(path)?;
+    if actual_hash.eq_ignore_ascii_case(expected_hash) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            expected_filename, expected_hash, actual_hash
+        ))
+    }
+}
+
+/// Download `url` to `dest`, emitting `compilation-progress` events under
+/// `job_id` the same way the compile pipeline does.
+async fn download_to(window: &tauri::Window, job_id: &str, url: &str, dest: &std::path::Path) -> Result<(), String> {
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 10,
+        message: format!("Downloading {}", url),
+        stage: "downloading".to_string(),
+    }).ok();
+
+    let response = reqwest::get(url).await.map_err(|e| format!("Failed to connect to {}: {}", url, e))?;
+    if !response.status().is_success() {
+        return Err(format!("Download failed ({}): {}", response.status(), url));
+    }
+
+    let bytes = response.bytes().await.map_err(|e| format!("Failed to download {}: {}", url, e))?;
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 60,
+        message: format!("Downloaded {} bytes", bytes.len()),
+        stage: "downloading".to_string(),
+    }).ok();
+
+    tokio::fs::write(dest, &bytes).await.map_err(|e| format!("Failed to write {}: {}", dest.display(), e))?;
+    Ok(())
+}
+
+/// Unpack `archive` (`.tar.gz` or `.zip`) into `dest_dir`, stripping the
+/// single top-level directory both python-build-standalone and Node.js
+/// tarballs wrap their contents in.
+fn extract_archive(archive: &std::path::Path, dest_dir: &std::path::Path) -> Result<(), String> {
+    std::fs::create_dir_all(dest_dir).map_err(|e| format!("Failed to create {}: {}", dest_dir.display(), e))?;
+
+    if archive.extension().and_then(|e| e.to_str()) == Some("zip") {
+        let file = std::fs::File::open(archive).map_err(|e| format!("Failed to open {}: {}", archive.display(), e))?;
+        let mut zip = zip::ZipArchive::new(file).map_err(|e| format!("Failed to read archive: {}", e))?;
+        for i in 0..zip.len() {
+            let mut entry = zip.by_index(i).map_err(|e| format!("Failed to read archive entry: {}", e))?;
+            let Some(enclosed) = entry.enclosed_name() else { continue };
+            let stripped = enclosed.components().skip(1).collect::<std::path::PathBuf>();
+            if stripped.as_os_str().is_empty() {
+                continue;
+            }
+            let outpath = dest_dir.join(stripped);
+            if entry.name().ends_with('/') {
+                std::fs::create_dir_all(&outpath).ok();
+            } else {
+                if let Some(parent) = outpath.parent() {
+                    std::fs::create_dir_all(parent).ok();
+                }
+                let mut outfile = std::fs::File::create(&outpath).map_err(|e| format!("Failed to create {}: {}", outpath.display(), e))?;
+                std::io::copy(&mut entry, &mut outfile).map_err(|e| format!("Failed to extract {}: {}", outpath.display(), e))?;
+            }
+        }
+    } else {
+        let file = std::fs::File::open(archive).map_err(|e| format!("Failed to open {}: {}", archive.display(), e))?;
+        let mut tarball = tar::Archive::new(flate2::read::GzDecoder::new(file));
+        tarball.set_preserve_permissions(true);
+        for entry in tarball.entries().map_err(|e| format!("Failed to read archive: {}", e))? {
+            let mut entry = entry.map_err(|e| format!("Failed to read archive entry: {}", e))?;
+            let path = entry.path().map_err(|e| format!("Invalid archive entry: {}", e))?;
+            let stripped = path.components().skip(1).collect::<std::path::PathBuf>();
+            if stripped.as_os_str().is_empty() {
+                continue;
+            }
+            entry.unpack(dest_dir.join(&stripped)).map_err(|e| format!("Failed to extract {}: {}", stripped.display(), e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Download, verify, and unpack a self-contained Python interpreter of
+/// `version` (e.g. `"3.12.3"`) into the app-managed runtime cache, so
+/// `toolchain::resolve_installations` picks it up as another candidate.
+#[tauri::command]
+pub async fn bootstrap_python(window: tauri::Window, version: String) -> Result<String, String> {
+    let job_id = uuid::Uuid::new_v4().to_string();
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(),
+        progress: 0,
+        message: format!("Bootstrapping Python {}...", version),
+        stage: "init".to_string(),
+    }).ok();
+
+    let result = bootstrap_python_inner(&window, &job_id, &version).await;
+
+    match &result {
+        Ok(path) => {
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(),
+                success: true,
+                output_path: Some(path.clone()),
+                error_message: None,
+            }).ok();
+        }
+        Err(e) => {
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(),
+                success: false,
+                output_path: None,
+                error_message: Some(e.clone()),
+            }).ok();
+        }
+    }
+
+    result
+}
+
+async fn bootstrap_python_inner(window: &tauri::Window, job_id: &str, version: &str) -> Result<String, String> {
+    let triple = python_build_standalone_triple()?;
+    // python-build-standalone tags releases by the date they were cut
+    // (e.g. "20240726") and bakes that same date into every asset filename
+    // as `+<date>` - there is no "latest" tag or `+latest` asset, so the
+    // real tag has to be resolved through the GitHub releases API first.
+    let tag = latest_python_build_standalone_tag().await?;
+    let filename = format!("cpython-{}+{}-{}-install_only.tar.gz", version, tag, triple);
+    let url = format!(
+        "https://github.com/indygreg/python-build-standalone/releases/download/{}/{}",
+        tag, filename
+    );
+
+    let cache_dir = toolchain::managed_runtime_root().join("python").join(version);
+    let archive_path = cache_dir.join(&filename);
+    std::fs::create_dir_all(&cache_dir).map_err(|e| format!("Failed to create {}: {}", cache_dir.display(), e))?;
+
+    download_to(window, job_id, &url, &archive_path).await?;
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 70,
+        message: format!("Verifying checksum for {}", filename),
+        stage: "verifying".to_string(),
+    }).ok();
+
+    let checksum_url = format!("{}.sha256", url);
+    let checksum_response = reqwest::get(&checksum_url)
+        .await
+        .map_err(|e| format!("Failed to fetch checksum from {}: {}", checksum_url, e))?;
+    if !checksum_response.status().is_success() {
+        return Err(format!("Checksum lookup failed for {} ({})", checksum_url, checksum_response.status()));
+    }
+    let checksum_text = checksum_response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read checksum response from {}: {}", checksum_url, e))?;
+    verify_against_checksum_list(&archive_path, &checksum_text, &filename)?;
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 85,
+        message: "Extracting Python runtime...".to_string(),
+        stage: "extracting".to_string(),
+    }).ok();
+
+    extract_archive(&archive_path, &cache_dir)?;
+    std::fs::remove_file(&archive_path).ok();
+
+    let interpreter = toolchain::managed_runtime_interpreter(ToolKind::Python, &cache_dir);
+    if !interpreter.exists() {
+        return Err(format!("Extraction succeeded but interpreter not found at {}", interpreter.display()));
+    }
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 100,
+        message: "Python runtime ready".to_string(),
+        stage: "complete".to_string(),
+    }).ok();
+
+    Ok(interpreter.to_string_lossy().to_string())
+}
+
+/// Download, verify, and unpack a self-contained Node.js runtime of
+/// `version` (e.g. `"20.11.0"`) into the app-managed runtime cache, so
+/// `toolchain::resolve_installations` picks it up as another candidate.
+#[tauri::command]
+pub async fn bootstrap_node(window: tauri::Window, version: String) -> Result<String, String> {
+    let job_id = uuid::Uuid::new_v4().to_string();
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.clone(),
+        progress: 0,
+        message: format!("Bootstrapping Node.js {}...", version),
+        stage: "init".to_string(),
+    }).ok();
+
+    let result = bootstrap_node_inner(&window, &job_id, &version).await;
+
+    match &result {
+        Ok(path) => {
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(),
+                success: true,
+                output_path: Some(path.clone()),
+                error_message: None,
+            }).ok();
+        }
+        Err(e) => {
+            window.emit("compilation-result", CompilationResult {
+                job_id: job_id.clone(),
+                success: false,
+                output_path: None,
+                error_message: Some(e.clone()),
+            }).ok();
+        }
+    }
+
+    result
+}
+
+async fn bootstrap_node_inner(window: &tauri::Window, job_id: &str, version: &str) -> Result<String, String> {
+    let (os_component, arch_component) = node_dist_components()?;
+    let ext = archive_extension();
+    let basename = format!("node-v{}-{}-{}", version, os_component, arch_component);
+    let filename = format!("{}.{}", basename, ext);
+    let url = format!("https://nodejs.org/dist/v{}/{}", version, filename);
+
+    let cache_dir = toolchain::managed_runtime_root().join("node").join(version);
+    let archive_path = cache_dir.join(&filename);
+    std::fs::create_dir_all(&cache_dir).map_err(|e| format!("Failed to create {}: {}", cache_dir.display(), e))?;
+
+    download_to(window, job_id, &url, &archive_path).await?;
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 70,
+        message: "Verifying checksum against SHASUMS256.txt".to_string(),
+        stage: "verifying".to_string(),
+    }).ok();
+
+    let checksums_url = format!("https://nodejs.org/dist/v{}/SHASUMS256.txt", version);
+    let checksums_response = reqwest::get(&checksums_url)
+        .await
+        .map_err(|e| format!("Failed to fetch checksums from {}: {}", checksums_url, e))?;
+    if !checksums_response.status().is_success() {
+        return Err(format!("Checksum lookup failed for {} ({})", checksums_url, checksums_response.status()));
+    }
+    let checksums_text = checksums_response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read checksums response from {}: {}", checksums_url, e))?;
+    verify_against_checksum_list(&archive_path, &checksums_text, &filename)?;
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 85,
+        message: "Extracting Node.js runtime...".to_string(),
+        stage: "extracting".to_string(),
+    }).ok();
+
+    extract_archive(&archive_path, &cache_dir)?;
+    std::fs::remove_file(&archive_path).ok();
+
+    let interpreter = toolchain::managed_runtime_interpreter(ToolKind::Node, &cache_dir);
+    if !interpreter.exists() {
+        return Err(format!("Extraction succeeded but node binary not found at {}", interpreter.display()));
+    }
+
+    window.emit("compilation-progress", CompilationProgress {
+        job_id: job_id.to_string(),
+        progress: 100,
+        message: "Node.js runtime ready".to_string(),
+        stage: "complete".to_string(),
+    }).ok();
+
+    Ok(interpreter.to_string_lossy().to_string())
+}
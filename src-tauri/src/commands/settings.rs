@@ -1,7 +1,10 @@
 // Application settings commands
+use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::db::DbCtx;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppSettings {
     pub theme: String,
@@ -21,26 +24,60 @@ impl Default for AppSettings {
     }
 }
 
+/// Read every row of the `settings` key/value table into a map, for
+/// building [`AppSettings`] or answering ad-hoc lookups.
+fn read_all_settings(db: &DbCtx) -> Result<HashMap<String, String>, String> {
+    let conn = db.get()?;
+    let mut stmt = conn
+        .prepare("SELECT key, value FROM settings")
+        .map_err(|e| format!("Failed to query settings: {e}"))?;
+
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+        .map_err(|e| format!("Failed to query settings: {e}"))?;
+
+    rows.collect::<rusqlite::Result<HashMap<_, _>>>().map_err(|e| format!("Failed to read settings: {e}"))
+}
+
+/// Upsert a single `key`/`value` pair into the `settings` table.
+fn write_setting(db: &DbCtx, key: &str, value: &str) -> Result<(), String> {
+    let conn = db.get()?;
+    conn.execute(
+        "INSERT INTO settings (key, value, updated_at) VALUES (?1, ?2, CURRENT_TIMESTAMP)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        params![key, value],
+    )
+    .map_err(|e| format!("Failed to save setting '{key}': {e}"))?;
+    Ok(())
+}
+
 /// Get all application settings
 #[tauri::command]
-pub async fn get_settings() -> Result<AppSettings, String> {
-    // TODO: Read from SQLite database
-    Ok(AppSettings::default())
+pub async fn get_settings(db: tauri::State<'_, DbCtx>) -> Result<AppSettings, String> {
+    let stored = read_all_settings(&db)?;
+    let defaults = AppSettings::default();
+
+    Ok(AppSettings {
+        theme: stored.get("theme").cloned().unwrap_or(defaults.theme),
+        api_url: stored.get("api_url").cloned().unwrap_or(defaults.api_url),
+        nuitka_path: stored.get("nuitka_path").cloned().unwrap_or(defaults.nuitka_path),
+        python_path: stored.get("python_path").cloned().or(defaults.python_path),
+    })
 }
 
 /// Update a single setting
 #[tauri::command]
-pub async fn update_setting(key: String, value: String) -> Result<bool, String> {
-    // TODO: Update in SQLite database
-    let _ = (key, value);
+pub async fn update_setting(db: tauri::State<'_, DbCtx>, key: String, value: String) -> Result<bool, String> {
+    write_setting(&db, &key, &value)?;
     Ok(true)
 }
 
 /// Update multiple settings at once
 #[tauri::command]
-pub async fn update_settings(settings: HashMap<String, String>) -> Result<bool, String> {
-    // TODO: Update in SQLite database
-    let _ = settings;
+pub async fn update_settings(db: tauri::State<'_, DbCtx>, settings: HashMap<String, String>) -> Result<bool, String> {
+    for (key, value) in &settings {
+        write_setting(&db, key, value)?;
+    }
     Ok(true)
 }
 
@@ -69,3 +106,12 @@ pub async fn set_compiler_path(path: String) -> Result<bool, String> {
     let _ = path;
     Ok(true)
 }
+
+/// Report the resolved location of the local SQLite database, so users
+/// chasing sync/backup issues can find exactly which file is in use.
+/// Honors the `CODEVAULT_DATA_DIR` override the same way the database
+/// itself does.
+#[tauri::command]
+pub fn get_db_location() -> String {
+    crate::db::get_db_path().to_string_lossy().to_string()
+}
@@ -0,0 +1,151 @@
+// `dutree`-style size breakdown of the files slated for an installer,
+// so stray debug symbols or duplicated assets show up before the NSIS
+// step runs rather than in a surprisingly large shipped artifact.
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// One node of the hierarchical size tree: a file, or a directory whose
+/// `bytes` is the sum of everything under it.
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeNode {
+    pub name: String,
+    pub bytes: u64,
+    pub percent_of_total: f64,
+    pub is_dir: bool,
+    pub children: Vec<SizeNode>,
+}
+
+/// Result of [`analyze_install_size`]: the full hierarchy plus a flat
+/// top-N of the largest individual files, for a UI that wants a quick
+/// "what's actually big here" list without walking the tree itself.
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeReport {
+    pub root: SizeNode,
+    pub total_bytes: u64,
+    pub largest_files: Vec<(String, u64)>,
+}
+
+const DEFAULT_AGGREGATE_THRESHOLD: f64 = 1.0;
+const LARGEST_FILES_LIMIT: usize = 20;
+
+/// Walk every file under `path` (skipping unreadable entries rather than
+/// failing the whole scan) and sum its size.
+fn dir_size(path: &Path) -> u64 {
+    let mut total = 0u64;
+    let Ok(entries) = std::fs::read_dir(path) else { return 0 };
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        if entry_path.is_dir() {
+            total += dir_size(&entry_path);
+        } else if let Ok(metadata) = entry.metadata() {
+            total += metadata.len();
+        }
+    }
+    total
+}
+
+/// Build a [`SizeNode`] for `path`, collapsing any directory's children
+/// whose individual share of `total_bytes` falls below
+/// `aggregate_threshold` into a single summary entry - the dutree
+/// `--aggregate-threshold` behavior.
+fn build_node(path: &Path, name: String, total_bytes: u64, aggregate_threshold: f64) -> SizeNode {
+    if path.is_file() {
+        let bytes = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        return SizeNode {
+            name,
+            bytes,
+            percent_of_total: percent(bytes, total_bytes),
+            is_dir: false,
+            children: Vec::new(),
+        };
+    }
+
+    let mut entries: Vec<(PathBuf, String)> = std::fs::read_dir(path)
+        .map(|rd| {
+            rd.flatten()
+                .map(|e| (e.path(), e.file_name().to_string_lossy().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by_key(|(p, _)| p.clone());
+
+    let mut children: Vec<SizeNode> = entries
+        .into_iter()
+        .map(|(child_path, child_name)| build_node(&child_path, child_name, total_bytes, aggregate_threshold))
+        .collect();
+    children.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+
+    let mut visible = Vec::new();
+    let mut collapsed_bytes = 0u64;
+    let mut collapsed_count = 0usize;
+    for child in children {
+        if percent(child.bytes, total_bytes) < aggregate_threshold {
+            collapsed_bytes += child.bytes;
+            collapsed_count += 1;
+        } else {
+            visible.push(child);
+        }
+    }
+    if collapsed_count > 0 {
+        visible.push(SizeNode {
+            name: format!("({collapsed_count} smaller entries)"),
+            bytes: collapsed_bytes,
+            percent_of_total: percent(collapsed_bytes, total_bytes),
+            is_dir: false,
+            children: Vec::new(),
+        });
+    }
+
+    let bytes: u64 = visible.iter().map(|c| c.bytes).sum();
+    SizeNode { name, bytes, percent_of_total: percent(bytes, total_bytes), is_dir: true, children: visible }
+}
+
+fn percent(bytes: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (bytes as f64 / total as f64) * 100.0
+    }
+}
+
+/// Collect every file under `path` with its size, for ranking the
+/// largest individual files regardless of which directory they're in.
+fn collect_files(path: &Path, out: &mut Vec<(String, u64)>) {
+    let Ok(entries) = std::fs::read_dir(path) else { return };
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        if entry_path.is_dir() {
+            collect_files(&entry_path, out);
+        } else if let Ok(metadata) = entry.metadata() {
+            out.push((entry_path.to_string_lossy().to_string(), metadata.len()));
+        }
+    }
+}
+
+/// Walk `project_path` and produce a hierarchical size report: per-
+/// directory aggregated bytes, each file's percent share of the total,
+/// and the largest individual files, with small entries under
+/// `aggregate_threshold` (percent, default 1%) collapsed into a summary
+/// line per directory.
+#[tauri::command]
+pub async fn analyze_install_size(
+    project_path: String,
+    aggregate_threshold: Option<f64>,
+) -> Result<SizeReport, String> {
+    let path = PathBuf::from(&project_path);
+    if !path.exists() {
+        return Err(format!("Path not found: {project_path}"));
+    }
+
+    let threshold = aggregate_threshold.unwrap_or(DEFAULT_AGGREGATE_THRESHOLD);
+    let total_bytes = dir_size(&path);
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| project_path.clone());
+    let root = build_node(&path, name, total_bytes, threshold);
+
+    let mut files = Vec::new();
+    collect_files(&path, &mut files);
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+    files.truncate(LARGEST_FILES_LIMIT);
+
+    Ok(SizeReport { root, total_bytes, largest_files: files })
+}
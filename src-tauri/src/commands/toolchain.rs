@@ -0,0 +1,364 @@
+// Interpreter/runtime discovery. Every prerequisite check and compile step
+// used to hardcode `Command::new("python")` / `Command::new("node")`, which
+// breaks when the binary is only installed as `python3`, reachable via the
+// Windows `py` launcher, or when the app is launched from Finder/Dock on
+// macOS (GUI processes don't inherit the login shell's PATH). This module
+// resolves each tool by trying an ordered list of candidate names against a
+// `which`-style scan of PATH plus well-known install locations, returning
+// the absolute path and reported version for every hit.
+use std::path::{Path, PathBuf};
+
+/// An interpreter/runtime found on this machine.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedTool {
+    pub path: String,
+    pub version: String,
+}
+
+/// Which toolchain to resolve. `candidates()` lists the program names tried,
+/// in priority order, plus any fixed arguments (e.g. the Windows `py`
+/// launcher needs `-3` before `--version` to target Python 3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolKind {
+    Python,
+    Node,
+}
+
+struct Candidate {
+    program: &'static str,
+    extra_args: &'static [&'static str],
+}
+
+impl ToolKind {
+    fn candidates(self) -> Vec<Candidate> {
+        match self {
+            ToolKind::Python => {
+                let mut candidates = vec![
+                    Candidate { program: "python", extra_args: &[] },
+                    Candidate { program: "python3", extra_args: &[] },
+                ];
+                if cfg!(windows) {
+                    candidates.push(Candidate { program: "py", extra_args: &["-3"] });
+                }
+                candidates
+            }
+            ToolKind::Node => vec![Candidate { program: "node", extra_args: &[] }],
+        }
+    }
+}
+
+/// The PATH a `Command::new(program)` call should search, repaired for
+/// macOS GUI launches. Exposed so callers that still shell out by name
+/// (e.g. via `sh -c`/`powershell -Command`) can set it as the child's
+/// environment instead of inheriting our possibly-broken one.
+pub fn search_path() -> String {
+    #[cfg(target_os = "macos")]
+    {
+        reconstruct_login_path()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        std::env::var("PATH").unwrap_or_default()
+    }
+}
+
+/// Reconstruct PATH the way a macOS login shell would. launchd hands GUI
+/// apps a minimal PATH, not the one a Terminal session builds up from
+/// `~/.zprofile`/`~/.bash_profile`-style files, so Homebrew-installed
+/// interpreters are invisible unless we read those dotfiles ourselves and
+/// prepend Homebrew's own prefixes regardless of what they say.
+#[cfg(target_os = "macos")]
+fn reconstruct_login_path() -> String {
+    let mut dirs = vec![
+        "/opt/homebrew/bin".to_string(),
+        "/opt/homebrew/sbin".to_string(),
+        "/usr/local/bin".to_string(),
+        "/usr/local/sbin".to_string(),
+    ];
+
+    if let Ok(home) = std::env::var("HOME") {
+        for profile in [".zprofile", ".zshrc", ".bash_profile", ".profile"] {
+            if let Ok(contents) = std::fs::read_to_string(PathBuf::from(&home).join(profile)) {
+                dirs.extend(extract_path_exports(&contents));
+            }
+        }
+    }
+
+    dirs.push(std::env::var("PATH").unwrap_or_default());
+    dirs.join(":")
+}
+
+/// Pull directory entries out of `export PATH=...` / `PATH=...` lines in a
+/// shell profile. Covers the common `$PATH`-extending idiom; anything more
+/// exotic (conditionals, `eval $(brew shellenv)`) is out of scope.
+#[cfg(target_os = "macos")]
+fn extract_path_exports(contents: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        let rhs = line.strip_prefix("export PATH=").or_else(|| line.strip_prefix("PATH="));
+        if let Some(rhs) = rhs {
+            let rhs = rhs.trim_matches('"').trim_matches('\'');
+            for part in rhs.split(':') {
+                if !part.is_empty() && part != "$PATH" {
+                    dirs.push(part.to_string());
+                }
+            }
+        }
+    }
+    dirs
+}
+
+#[cfg(unix)]
+fn is_executable_file(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn is_executable_file(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Standard per-OS install locations worth checking even when they're not
+/// on PATH, because a package installer put them there without updating it.
+fn well_known_dirs(program: &str) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if cfg!(target_os = "windows") {
+        if program == "node" {
+            found.push(PathBuf::from(r"C:\Program Files\nodejs\node.exe"));
+        }
+        if program.starts_with("python") {
+            if let Ok(local_appdata) = std::env::var("LOCALAPPDATA") {
+                let versions_dir = PathBuf::from(local_appdata).join("Programs").join("Python");
+                if let Ok(entries) = std::fs::read_dir(&versions_dir) {
+                    for entry in entries.flatten() {
+                        found.push(entry.path().join("python.exe"));
+                    }
+                }
+            }
+        }
+    } else {
+        found.push(PathBuf::from("/opt/homebrew/bin").join(program));
+        found.push(PathBuf::from("/usr/local/bin").join(program));
+        found.push(PathBuf::from("/usr/bin").join(program));
+    }
+    found
+}
+
+/// Find the first `program` on `search_path`, falling back to well-known
+/// install locations if PATH doesn't have it.
+fn find_executable(program: &str, search_path: &str) -> Option<PathBuf> {
+    for dir in std::env::split_paths(search_path) {
+        let candidate = dir.join(program);
+        if is_executable_file(&candidate) {
+            return Some(candidate);
+        }
+        if cfg!(windows) {
+            for ext in ["exe", "cmd", "bat"] {
+                let with_ext = dir.join(format!("{program}.{ext}"));
+                if with_ext.is_file() {
+                    return Some(with_ext);
+                }
+            }
+        }
+    }
+    well_known_dirs(program).into_iter().find(|p| is_executable_file(p))
+}
+
+/// Run `path` with `extra_args` followed by `--version` and return its
+/// reported version, or `None` if it didn't start or exit cleanly.
+async fn probe_version(path: &Path, extra_args: &[&str]) -> Option<String> {
+    let mut args: Vec<&str> = extra_args.to_vec();
+    args.push("--version");
+    let output = tokio::process::Command::new(path).args(&args).output().await.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let raw = if !output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+    Some(raw.trim().to_string())
+}
+
+/// Root directory bootstrapped (self-contained) runtimes are unpacked
+/// into, keyed `<tool>/<version>/...`, so they survive across app restarts
+/// instead of being re-downloaded every time.
+pub fn managed_runtime_root() -> PathBuf {
+    let base = std::env::var("LOCALAPPDATA")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(std::env::var("HOME").unwrap_or_default()));
+    base.join(".codevault_runtimes")
+}
+
+/// The interpreter/binary a bootstrapped `<tool>/<version>` directory
+/// holds, matching the unpack layout `runtime_bootstrap` writes.
+pub fn managed_runtime_interpreter(tool: ToolKind, version_dir: &Path) -> PathBuf {
+    match tool {
+        ToolKind::Python if cfg!(windows) => version_dir.join("python.exe"),
+        ToolKind::Python => version_dir.join("bin").join("python3"),
+        ToolKind::Node if cfg!(windows) => version_dir.join("node.exe"),
+        ToolKind::Node => version_dir.join("bin").join("node"),
+    }
+}
+
+/// Every bootstrapped runtime found under `managed_runtime_root()` for
+/// `tool`, one per installed version directory.
+fn managed_runtime_candidates(tool: ToolKind) -> Vec<PathBuf> {
+    let subdir = match tool {
+        ToolKind::Python => "python",
+        ToolKind::Node => "node",
+    };
+    let versions_dir = managed_runtime_root().join(subdir);
+    let mut found = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(&versions_dir) {
+        for entry in entries.flatten() {
+            let interpreter = managed_runtime_interpreter(tool, &entry.path());
+            if is_executable_file(&interpreter) {
+                found.push(interpreter);
+            }
+        }
+    }
+    found
+}
+
+/// Resolve every distinct installation of `tool` found on this machine -
+/// on PATH, in well-known install locations, and in the app-managed
+/// bootstrap cache - in priority order, so the UI can offer a picker when
+/// more than one is present. Each candidate is confirmed by actually
+/// running it with a version flag, not just found on disk.
+pub async fn resolve_installations(tool: ToolKind) -> Vec<ResolvedTool> {
+    let search = search_path();
+    let mut seen = std::collections::HashSet::new();
+    let mut found = Vec::new();
+
+    for candidate in tool.candidates() {
+        let Some(path) = find_executable(candidate.program, &search) else { continue };
+        let path_str = path.to_string_lossy().to_string();
+        if !seen.insert(path_str.clone()) {
+            continue;
+        }
+        if let Some(version) = probe_version(&path, candidate.extra_args).await {
+            found.push(ResolvedTool { path: path_str, version });
+        }
+    }
+
+    for path in managed_runtime_candidates(tool) {
+        let path_str = path.to_string_lossy().to_string();
+        if !seen.insert(path_str.clone()) {
+            continue;
+        }
+        if let Some(version) = probe_version(&path, &[]).await {
+            found.push(ResolvedTool { path: path_str, version });
+        }
+    }
+
+    found
+}
+
+/// The installation we'd actually use to run a compile: the first
+/// (highest-priority) hit from `resolve_installations`.
+pub async fn preferred(tool: ToolKind) -> Option<ResolvedTool> {
+    resolve_installations(tool).await.into_iter().next()
+}
+
+/// `preferred(tool)`'s path, or the tool's bare name if nothing resolved -
+/// preserves today's behavior of letting the OS report "not found" rather
+/// than failing resolution itself.
+pub async fn preferred_path_or_bare(tool: ToolKind) -> String {
+    preferred(tool)
+        .await
+        .map(|t| t.path)
+        .unwrap_or_else(|| match tool {
+            ToolKind::Python => "python".to_string(),
+            ToolKind::Node => "node".to_string(),
+        })
+}
+
+/// A detected project-local virtual environment: its interpreter path and
+/// the parent (`base_prefix`) interpreter it was created from.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VenvInfo {
+    pub interpreter_path: String,
+    pub base_prefix: String,
+}
+
+/// Probe `candidate` with `sys.prefix`/`sys.base_prefix` to decide whether
+/// it's a virtual environment interpreter, returning its `base_prefix`
+/// (the parent interpreter it was created from) when it is.
+async fn probe_venv(candidate: &Path) -> Option<String> {
+    let output = tokio::process::Command::new(candidate)
+        .args(["-c", "import sys; print(sys.prefix); print(sys.base_prefix)"])
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let prefix = lines.next()?.trim();
+    let base_prefix = lines.next()?.trim();
+    // Venv executables on Windows are shim launchers, not symlinks, so
+    // canonicalizing the path and comparing gives the wrong answer -
+    // compare the reported prefixes themselves instead.
+    if prefix != base_prefix {
+        Some(base_prefix.to_string())
+    } else {
+        None
+    }
+}
+
+/// The interpreter inside a venv directory: `dir/bin/python*` on Unix,
+/// `dir\Scripts\python.exe` on Windows.
+fn venv_interpreter_path(venv_dir: &Path) -> Option<PathBuf> {
+    if cfg!(windows) {
+        let candidate = venv_dir.join("Scripts").join("python.exe");
+        candidate.is_file().then_some(candidate)
+    } else {
+        for name in ["python3", "python"] {
+            let candidate = venv_dir.join("bin").join(name);
+            if is_executable_file(&candidate) {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+/// Look for a project-local virtual environment (`.venv`/`venv`/`env`
+/// containing a `pyvenv.cfg`), confirming it's really a venv via
+/// `sys.prefix != sys.base_prefix` rather than trusting the directory name
+/// alone.
+pub async fn detect_project_venv(project_path: &Path) -> Option<VenvInfo> {
+    for dir_name in [".venv", "venv", "env"] {
+        let venv_dir = project_path.join(dir_name);
+        if !venv_dir.join("pyvenv.cfg").is_file() {
+            continue;
+        }
+        let Some(interpreter) = venv_interpreter_path(&venv_dir) else { continue };
+        if let Some(base_prefix) = probe_venv(&interpreter).await {
+            return Some(VenvInfo {
+                interpreter_path: interpreter.to_string_lossy().to_string(),
+                base_prefix,
+            });
+        }
+    }
+    None
+}
+
+/// List every Python installation found, for a UI picker.
+#[tauri::command]
+pub async fn list_python_installations() -> Result<Vec<ResolvedTool>, String> {
+    Ok(resolve_installations(ToolKind::Python).await)
+}
+
+/// List every Node.js installation found, for a UI picker.
+#[tauri::command]
+pub async fn list_node_installations() -> Result<Vec<ResolvedTool>, String> {
+    Ok(resolve_installations(ToolKind::Node).await)
+}
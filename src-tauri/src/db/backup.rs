@@ -0,0 +1,127 @@
+// Portable JSON backup/restore for the local database, independent of the
+// SQLite binary layout so backups survive schema migrations.
+use rusqlite::{types::ValueRef, Connection, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::io::{Read, Write};
+
+use super::Database;
+
+/// Tables included in a dump, in dependency order (projects before the
+/// rows that reference them) so `import` can insert safely.
+const DUMP_TABLES: &[&str] = &["projects", "settings", "license_cache", "kv_store"];
+
+/// Self-contained backup document. Each table is stored as a list of
+/// `{column: value}` objects rather than typed rows, so a dump taken
+/// before a schema migration can still be read after one.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseDump {
+    pub tables: Map<String, Value>,
+}
+
+impl Database {
+    /// Serialize `projects`, `settings`, `license_cache`, and `kv_store`
+    /// to a self-contained JSON document written to `writer`.
+    pub fn dump(&self, mut writer: impl Write) -> Result<()> {
+        let conn = self.connection();
+        let mut tables = Map::new();
+
+        for &table in DUMP_TABLES {
+            tables.insert(table.to_string(), Value::Array(dump_table(&conn, table)?));
+        }
+
+        let dump = DatabaseDump { tables };
+        serde_json::to_writer_pretty(&mut writer, &dump)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+    }
+
+    /// Restore a document produced by [`Database::dump`]. When `merge` is
+    /// `true`, rows are upserted by primary key alongside existing data;
+    /// when `false`, each table is cleared first so the import fully
+    /// replaces its contents. Runs inside a single transaction so a
+    /// malformed document can't leave the database half-restored.
+    pub fn import(&self, mut reader: impl Read, merge: bool) -> Result<()> {
+        let mut json = String::new();
+        reader
+            .read_to_string(&mut json)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+        let dump: DatabaseDump = serde_json::from_str(&json)
+            .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e)))?;
+
+        let mut conn = self.connection();
+        let tx = conn.transaction()?;
+
+        for &table in DUMP_TABLES {
+            let Some(Value::Array(rows)) = dump.tables.get(table) else {
+                continue;
+            };
+
+            if !merge {
+                tx.execute(&format!("DELETE FROM {table}"), [])?;
+            }
+
+            for row in rows {
+                let Value::Object(columns) = row else { continue };
+                import_row(&tx, table, columns)?;
+            }
+        }
+
+        tx.commit()
+    }
+}
+
+/// Read every row of `table` into a JSON object keyed by column name.
+fn dump_table(conn: &Connection, table: &str) -> Result<Vec<Value>> {
+    let mut stmt = conn.prepare(&format!("SELECT * FROM {table}"))?;
+    let column_names: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+
+    let rows = stmt.query_map([], move |row| {
+        let mut obj = Map::new();
+        for (i, name) in column_names.iter().enumerate() {
+            let value = match row.get_ref(i)? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(n) => Value::from(n),
+                ValueRef::Real(f) => Value::from(f),
+                ValueRef::Text(t) => Value::from(String::from_utf8_lossy(t).to_string()),
+                ValueRef::Blob(b) => Value::from(b.to_vec()),
+            };
+            obj.insert(name.clone(), value);
+        }
+        Ok(Value::Object(obj))
+    })?;
+
+    rows.collect()
+}
+
+/// Upsert a single dumped row back into `table` using `INSERT OR REPLACE`,
+/// which works for both the merge and replace-all import modes since
+/// every dumped table has a single-column primary key.
+fn import_row(conn: &Connection, table: &str, columns: &Map<String, Value>) -> Result<()> {
+    let names: Vec<&str> = columns.keys().map(|k| k.as_str()).collect();
+    let placeholders: Vec<String> = (1..=names.len()).map(|i| format!("?{i}")).collect();
+
+    let sql = format!(
+        "INSERT OR REPLACE INTO {table} ({}) VALUES ({})",
+        names.join(", "),
+        placeholders.join(", ")
+    );
+
+    let params: Vec<Box<dyn rusqlite::ToSql>> = columns
+        .values()
+        .map(|v| -> Box<dyn rusqlite::ToSql> {
+            match v {
+                Value::Null => Box::new(Option::<String>::None),
+                Value::Bool(b) => Box::new(*b as i64),
+                Value::Number(n) if n.is_i64() => Box::new(n.as_i64().unwrap()),
+                Value::Number(n) => Box::new(n.as_f64().unwrap_or(0.0)),
+                Value::String(s) => Box::new(s.clone()),
+                other => Box::new(other.to_string()),
+            }
+        })
+        .collect();
+    let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+    conn.execute(&sql, param_refs.as_slice())?;
+    Ok(())
+}
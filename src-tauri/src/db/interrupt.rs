@@ -0,0 +1,57 @@
+// Cancellation support for long-running queries (full-text search, bulk
+// imports) so the UI can abort an in-flight statement instead of freezing.
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cloneable handle that can abort whatever statement is currently
+/// running on the `Connection` it was created from. Safe to send to
+/// another thread and call at any time, including while no query is
+/// running (the interrupt is simply a no-op then).
+#[derive(Clone)]
+pub struct InterruptHandle {
+    sqlite_handle: rusqlite::InterruptHandle,
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    pub(super) fn new(sqlite_handle: rusqlite::InterruptHandle) -> Self {
+        Self {
+            sqlite_handle,
+            interrupted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Abort the statement currently running on the owning connection with
+    /// `SQLITE_INTERRUPT`.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::SeqCst);
+        self.sqlite_handle.interrupt();
+    }
+
+    /// Whether `interrupt()` has been called since the last [`InterruptHandle::scope`].
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
+    /// Enter a scope for a new query: clears any stale interrupted flag
+    /// from a previous query and returns a guard that restores it to
+    /// "not interrupted" on drop. Callers should hold the returned
+    /// [`InterruptScope`] for the duration of the query they want to be
+    /// cancellable.
+    pub fn scope(&self) -> InterruptScope<'_> {
+        self.interrupted.store(false, Ordering::SeqCst);
+        InterruptScope { handle: self }
+    }
+}
+
+/// Guard marking the span of an interruptible query. Dropping it clears
+/// the interrupted flag so the next query starts from a clean state.
+pub struct InterruptScope<'a> {
+    handle: &'a InterruptHandle,
+}
+
+impl Drop for InterruptScope<'_> {
+    fn drop(&mut self) {
+        self.handle.interrupted.store(false, Ordering::SeqCst);
+    }
+}
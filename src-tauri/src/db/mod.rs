@@ -1,45 +1,152 @@
 // Database module for CodeVault local SQLite storage
+pub mod backup;
+pub mod interrupt;
+pub mod pool;
 pub mod schema;
 
-use rusqlite::{Connection, Result};
-use std::path::PathBuf;
+pub use backup::DatabaseDump;
+pub use interrupt::InterruptHandle;
+pub use pool::DbCtx;
+
+use rusqlite::{ffi::ErrorCode, Connection, Error as SqlError, Result};
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::fs;
 
-/// Get the path to the local database
+/// Get the path to the local database. Honors the `CODEVAULT_DATA_DIR`
+/// environment variable for portable installs, multiple vaults, and test
+/// isolation; falls back to the platform app-data directory.
 #[allow(dead_code)]
 pub fn get_db_path() -> PathBuf {
-    let app_data = dirs::data_local_dir().expect("Could not find app data directory");
-    let codevault_dir = app_data.join("CodeVault");
+    let codevault_dir = match std::env::var_os("CODEVAULT_DATA_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => dirs::data_local_dir()
+            .expect("Could not find app data directory")
+            .join("CodeVault"),
+    };
     fs::create_dir_all(&codevault_dir).ok();
     codevault_dir.join("codevault.db")
 }
 
+/// Whether opening the database found it intact or had to recover from a
+/// corrupt file on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryStatus {
+    /// The database opened and passed its integrity check normally.
+    Ok,
+    /// The existing file was corrupt and has been quarantined; a fresh
+    /// database was created in its place. The UI should warn the user
+    /// that their previously stored data is gone.
+    Recovered,
+}
+
 /// Database connection wrapper - thread-safe
 #[allow(dead_code)]
 pub struct Database {
     conn: Mutex<Connection>,
+    db_path: PathBuf,
 }
 
 #[allow(dead_code)]
 impl Database {
-    /// Create a new database connection
+    /// Create a new database connection at the default app-data location.
     pub fn new() -> Result<Self> {
-        let db_path = get_db_path();
-        let conn = Connection::open(db_path)?;
-        
-        // Initialize schema
-        schema::init_schema(&conn)?;
-        
+        Self::open(get_db_path()).map(|(db, _)| db)
+    }
+
+    /// Open (or create) the database at an arbitrary path instead of the
+    /// default app-data location, e.g. for tests or a user-chosen vault.
+    pub fn new_at(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open(path.as_ref().to_path_buf()).map(|(db, _)| db)
+    }
+
+    /// Open a named in-memory database with a shared cache, so multiple
+    /// connections within this process can see the same data. Intended
+    /// for unit tests and other ephemeral sessions that shouldn't touch
+    /// disk or depend on test ordering.
+    pub fn new_memory(name: &str) -> Result<Self> {
+        let uri = format!("file:{}?mode=memory&cache=shared", name);
+        let flags = rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE
+            | rusqlite::OpenFlags::SQLITE_OPEN_CREATE
+            | rusqlite::OpenFlags::SQLITE_OPEN_URI
+            | rusqlite::OpenFlags::SQLITE_OPEN_SHARED_CACHE;
+
+        let mut conn = Connection::open_with_flags(&uri, flags)?;
+        schema::run_migrations(&mut conn)?;
+
         Ok(Database {
             conn: Mutex::new(conn),
+            db_path: PathBuf::from(uri),
         })
     }
-    
+
+    /// Open (or create) the database at `db_path`, detecting and
+    /// recovering from corruption, and returning whether recovery ran so
+    /// the caller can surface a warning.
+    pub fn open(db_path: PathBuf) -> Result<(Self, RecoveryStatus)> {
+        let (conn, status) = open_with_recovery(&db_path)?;
+        Ok((
+            Database {
+                conn: Mutex::new(conn),
+                db_path,
+            },
+            status,
+        ))
+    }
+
     /// Get a reference to the connection for queries
     pub fn connection(&self) -> std::sync::MutexGuard<'_, Connection> {
         self.conn.lock().expect("Failed to acquire database lock")
     }
+
+    /// The resolved path of the file (or in-memory URI) this database was
+    /// opened from, so users hitting sync/backup issues can see exactly
+    /// which file is in use.
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+
+    /// Get a cloneable handle that can abort whatever query is currently
+    /// running on this database's connection, so callers (e.g. a search
+    /// box that re-queries on every keystroke) can cancel stale work.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle::new(self.connection().get_interrupt_handle())
+    }
+
+    /// Serialize `value` to JSON and persist it under `key` in the
+    /// generic `kv_store` table, overwriting any previous value.
+    pub fn store<T: serde::Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| SqlError::ToSqlConversionFailure(Box::new(e)))?;
+
+        self.connection().execute(
+            "INSERT INTO kv_store (key, value, updated_at) VALUES (?1, ?2, CURRENT_TIMESTAMP)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+            rusqlite::params![key, json],
+        )?;
+        Ok(())
+    }
+
+    /// Look up `key` in the generic `kv_store` table and deserialize it
+    /// from JSON, returning `None` if no value has been stored yet.
+    pub fn get<T: serde::de::DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let json: Option<String> = self
+            .connection()
+            .query_row(
+                "SELECT value FROM kv_store WHERE key = ?1",
+                [key],
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|e| if e == SqlError::QueryReturnedNoRows { Ok(None) } else { Err(e) })?;
+
+        match json {
+            Some(json) => serde_json::from_str(&json)
+                .map(Some)
+                .map_err(|e| SqlError::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))),
+            None => Ok(None),
+        }
+    }
 }
 
 impl Default for Database {
@@ -47,3 +154,65 @@ impl Default for Database {
         Self::new().expect("Failed to initialize database")
     }
 }
+
+/// Open `db_path`, run migrations, and verify the file isn't corrupt. If
+/// corruption is detected, quarantine the bad file and start fresh.
+fn open_with_recovery(db_path: &Path) -> Result<(Connection, RecoveryStatus)> {
+    let mut conn = Connection::open(db_path)?;
+
+    match schema::run_migrations(&mut conn).and_then(|_| integrity_check(&conn)) {
+        Ok(()) => Ok((conn, RecoveryStatus::Ok)),
+        Err(err) if is_corruption_error(&err) => {
+            drop(conn);
+            quarantine_corrupt_file(db_path)?;
+
+            let mut fresh = Connection::open(db_path)?;
+            schema::run_migrations(&mut fresh)?;
+            Ok((fresh, RecoveryStatus::Recovered))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Lightweight check that the database file is readable and not corrupt:
+/// `PRAGMA quick_check` followed by a trivial query against a core table.
+fn integrity_check(conn: &Connection) -> Result<()> {
+    let result: String = conn.query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+    if result != "ok" {
+        return Err(SqlError::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CORRUPT),
+            Some(result),
+        ));
+    }
+
+    conn.query_row("SELECT COUNT(*) FROM settings", [], |row| row.get::<_, i64>(0))?;
+    Ok(())
+}
+
+/// Whether `err` indicates the SQLite file itself is corrupt or not a
+/// valid database, as opposed to a query/constraint error.
+fn is_corruption_error(err: &SqlError) -> bool {
+    match err {
+        SqlError::SqliteFailure(e, _) => {
+            matches!(e.code, ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase)
+        }
+        _ => false,
+    }
+}
+
+/// Rename a corrupt database file out of the way, preserving it for
+/// forensics, so a fresh database can be created in its place.
+fn quarantine_corrupt_file(db_path: &Path) -> Result<()> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let quarantined = db_path.with_extension(format!("db.corrupt-{}", timestamp));
+    fs::rename(db_path, &quarantined).map_err(|e| {
+        SqlError::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CANTOPEN),
+            Some(format!("Failed to quarantine corrupt database: {}", e)),
+        )
+    })
+}
@@ -0,0 +1,63 @@
+// Pooled SQLite access for Tauri commands. `Database` (see `super`) wraps a
+// single `Mutex<Connection>` and is used by the backup/restore and
+// corruption-recovery paths, which only ever need one connection at a time.
+// Commands invoked concurrently from the frontend (project CRUD, settings
+// reads/writes) are better served by a small connection pool so one slow
+// query doesn't serialize every other command behind the same mutex - the
+// same split `DbCtx`/connection-pool separation as build-o-tron's
+// `dbctx.rs`/`sql.rs`.
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+
+use super::{get_db_path, open_with_recovery, schema, RecoveryStatus};
+
+/// Connection pool handle, managed as `tauri::State` and injected into
+/// every command that touches the database. Cheap to clone - the pool
+/// itself is reference-counted - so background workers (e.g. the
+/// analytics sync task) can hold their own handle alongside the one
+/// `tauri::State` hands to commands.
+#[derive(Clone)]
+pub struct DbCtx {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl DbCtx {
+    /// Open (or create) the database at the default app-data location,
+    /// build a connection pool against it, and run migrations once on a
+    /// connection checked out for that purpose. Call once at startup.
+    pub fn new() -> Result<(Self, RecoveryStatus), String> {
+        Self::new_at(get_db_path())
+    }
+
+    /// Same as [`DbCtx::new`] but against an arbitrary path, for tests or a
+    /// user-chosen vault.
+    pub fn new_at(db_path: impl Into<std::path::PathBuf>) -> Result<(Self, RecoveryStatus), String> {
+        let db_path = db_path.into();
+
+        // Run the same quick_check/quarantine-and-recreate recovery the
+        // plain `Database` gets before the pool ever touches the file -
+        // otherwise a corrupt file makes every pooled connection's
+        // migrations fail, and the app would never start.
+        let (conn, status) = open_with_recovery(&db_path).map_err(|e| format!("Failed to open database: {e}"))?;
+        drop(conn);
+
+        let manager = SqliteConnectionManager::file(&db_path);
+        let pool = Pool::builder()
+            .max_size(8)
+            .build(manager)
+            .map_err(|e| format!("Failed to build database pool: {e}"))?;
+
+        {
+            let mut conn = pool.get().map_err(|e| format!("Failed to check out connection: {e}"))?;
+            schema::run_migrations(&mut conn).map_err(|e| format!("Failed to run migrations: {e}"))?;
+        }
+
+        Ok((DbCtx { pool }, status))
+    }
+
+    /// Check out a pooled connection. Blocks the calling (async command's
+    /// blocking) thread if every connection is currently in use.
+    pub fn get(&self) -> Result<r2d2::PooledConnection<SqliteConnectionManager>, String> {
+        self.pool.get().map_err(|e| format!("Database pool exhausted: {e}"))
+    }
+}
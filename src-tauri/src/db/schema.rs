@@ -1,9 +1,20 @@
 // Database schema initialization
 use rusqlite::{Connection, Result};
 
-#[allow(dead_code)]
-pub fn init_schema(conn: &Connection) -> Result<()> {
-    conn.execute_batch(r#"
+/// A single forward-only schema migration, applied in order starting from
+/// `user_version + 1`. Each step's SQL must be safe to run against the
+/// state left by every prior step.
+struct Migration {
+    sql: &'static str,
+}
+
+/// Ordered list of schema migrations. The Nth entry (0-indexed) brings the
+/// database from `user_version == N` to `user_version == N + 1`. Append new
+/// migrations to the end; never reorder or edit an existing entry once it
+/// has shipped, or installs that already applied it will skip the change.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        sql: r#"
         -- Local projects (synced from cloud or created locally)
         CREATE TABLE IF NOT EXISTS projects (
             id TEXT PRIMARY KEY,
@@ -66,21 +77,69 @@ pub fn init_schema(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS idx_license_cache_project ON license_cache(project_id);
         CREATE INDEX IF NOT EXISTS idx_compile_history_project ON compile_history(project_id);
         CREATE INDEX IF NOT EXISTS idx_analytics_synced ON analytics_events(synced);
-    "#)?;
-    
-    // Insert default settings if not exist
-    conn.execute(
-        "INSERT OR IGNORE INTO settings (key, value) VALUES ('theme', 'dark')",
-        [],
-    )?;
-    conn.execute(
-        "INSERT OR IGNORE INTO settings (key, value) VALUES ('api_url', 'https://codevault.parth7.me/api/v1')",
-        [],
-    )?;
-    conn.execute(
-        "INSERT OR IGNORE INTO settings (key, value) VALUES ('nuitka_path', '')",
-        [],
-    )?;
-    
+
+        INSERT OR IGNORE INTO settings (key, value) VALUES ('theme', 'dark');
+        INSERT OR IGNORE INTO settings (key, value) VALUES ('api_url', 'https://codevault.parth7.me/api/v1');
+        INSERT OR IGNORE INTO settings (key, value) VALUES ('nuitka_path', '');
+        "#,
+    },
+    Migration {
+        sql: r#"
+        -- Generic typed key/value store backing Database::store/get, used
+        -- for ad-hoc metadata (recent searches, tag indexes, cached UI
+        -- state) that doesn't warrant its own table.
+        CREATE TABLE IF NOT EXISTS kv_store (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        "#,
+    },
+    Migration {
+        sql: r#"
+        -- Benchmark runner columns: how long a compile took and what
+        -- machine/toolchain it ran on, so performance can be compared
+        -- across runs instead of `compile_history` only recording outcome.
+        ALTER TABLE compile_history ADD COLUMN duration_ms INTEGER;
+        ALTER TABLE compile_history ADD COLUMN host_os TEXT;
+        ALTER TABLE compile_history ADD COLUMN host_cpu_model TEXT;
+        ALTER TABLE compile_history ADD COLUMN host_cpu_cores INTEGER;
+        ALTER TABLE compile_history ADD COLUMN host_ram_mb INTEGER;
+        ALTER TABLE compile_history ADD COLUMN python_version TEXT;
+        ALTER TABLE compile_history ADD COLUMN nuitka_version TEXT;
+        ALTER TABLE compile_history ADD COLUMN benchmark_tag TEXT;
+        "#,
+    },
+];
+
+/// Read the schema version stamped on the database via `PRAGMA user_version`.
+fn current_version(conn: &Connection) -> Result<u32> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// Apply every migration whose index is greater than the stored
+/// `user_version`, each inside its own transaction, bumping `user_version`
+/// as soon as it succeeds. Safe to call on every `Database::new` - a
+/// database already at the latest version runs no SQL.
+pub fn run_migrations(conn: &mut Connection) -> Result<()> {
+    let mut version = current_version(conn)? as usize;
+
+    while version < MIGRATIONS.len() {
+        let migration = &MIGRATIONS[version];
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration.sql)?;
+        let next_version = (version + 1) as u32;
+        tx.pragma_update(None, "user_version", next_version)?;
+        tx.commit()?;
+        version += 1;
+    }
+
     Ok(())
 }
+
+/// Initialize the schema on a fresh or existing database, bringing it up
+/// to the latest `user_version` via [`run_migrations`].
+#[allow(dead_code)]
+pub fn init_schema(conn: &mut Connection) -> Result<()> {
+    run_migrations(conn)
+}
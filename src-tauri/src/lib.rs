@@ -5,18 +5,38 @@ mod commands;
 mod db;
 mod backend_manager;
 
-use commands::{projects, settings, compiler, downloader};
+use commands::{projects, settings, compiler, downloader, jobs, toolchain, runtime_bootstrap, size_report, benchmark, analytics};
 
 /// Initialize and run the Tauri application
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let (db, recovery_status) = db::DbCtx::new().unwrap_or_else(|e| {
+        eprintln!("Fatal: failed to initialize database pool: {e}");
+        std::process::exit(1);
+    });
+    if recovery_status == db::RecoveryStatus::Recovered {
+        eprintln!("Warning: the existing database was corrupt and has been quarantined; a fresh database was created.");
+    }
+    let worker_db = db.clone();
+    let exit_db = db.clone();
+
+    let app = tauri::Builder::default()
         // Plugins
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
-        
+
+        // Shared state
+        .manage(jobs::JobRegistry::default())
+        .manage(db)
+
+        // Background workers
+        .setup(move |_app| {
+            analytics::spawn_worker(worker_db.clone());
+            Ok(())
+        })
+
         // Register all commands
         .invoke_handler(tauri::generate_handler![
             // Project commands
@@ -32,9 +52,11 @@ pub fn run() {
             settings::update_settings,
             settings::get_nuitka_path,
             settings::set_compiler_path,
+            settings::get_db_location,
             
             // Compiler commands
             compiler::run_nuitka_compilation,
+            compiler::run_nuitka_compilation_watch,
             compiler::run_nodejs_compilation,
             compiler::check_nuitka_installed,
             compiler::get_nuitka_version,
@@ -45,9 +67,13 @@ pub fn run() {
             compiler::detect_frontend,
             compiler::convert_png_to_ico,
             compiler::check_python_installed,
+            compiler::check_docker_installed,
             compiler::get_nuitka_status,
             compiler::install_nuitka,
             compiler::install_pillow,
+            compiler::save_profile,
+            compiler::load_profile,
+            compiler::stamp_license,
             // Node.js commands
             compiler::check_node_installed,
             compiler::check_pkg_installed,
@@ -56,9 +82,34 @@ pub fn run() {
             compiler::check_obfuscator_installed,
             // Professional Installer Build System
             compiler::run_installer_build,
+            compiler::verify_sidecar_bundled,
             // NSIS (Windows Installer) commands
             compiler::check_nsis_installed,
-            
+            // macOS/Linux installer tooling
+            compiler::check_pkgbuild_installed,
+            compiler::check_appimagetool_installed,
+            compiler::check_dpkg_deb_installed,
+            compiler::generate_installer_manifest,
+            size_report::analyze_install_size,
+            // Environment doctor
+            compiler::environment_report,
+            compiler::check_pypi_update,
+            // Android packaging (Capacitor + Gradle)
+            compiler::check_java_installed,
+            compiler::check_android_sdk_installed,
+            compiler::run_android_build,
+
+            // Job control commands
+            jobs::cancel_compile,
+
+            // Toolchain discovery commands
+            toolchain::list_python_installations,
+            toolchain::list_node_installations,
+
+            // Runtime bootstrap commands
+            runtime_bootstrap::bootstrap_python,
+            runtime_bootstrap::bootstrap_node,
+
             // Backend management commands
             backend_manager::check_backend_status,
             backend_manager::start_backend_service,
@@ -70,10 +121,21 @@ pub fn run() {
             downloader::cleanup_downloaded_project,
             downloader::download_and_prepare_for_compile,
             downloader::get_project_download_path,
+
+            // Benchmark/workload runner
+            benchmark::run_compile_benchmark,
+
+            // Analytics sync
+            analytics::track_event,
+            analytics::get_analytics_sync_status,
         ])
-        
-        // Run the app
-        .run(tauri::generate_context!())
-        .expect("error while running CodeVault application");
+        .build(tauri::generate_context!())
+        .expect("error while building CodeVault application");
+
+    app.run(move |_app_handle, event| {
+        if let tauri::RunEvent::Exit = event {
+            analytics::flush_on_exit(&exit_db);
+        }
+    });
 }
 